@@ -0,0 +1,76 @@
+//! Throughput of the UTC parsing path. Run with `cargo bench --bench utc_to_msd`.
+//!
+//! Counts heap allocations next to the timings, the parse path is expected to report none.
+
+use std::alloc::{ GlobalAlloc, Layout, System };
+use std::hint::black_box;
+use std::sync::atomic::{ AtomicUsize, Ordering };
+use std::time::Instant;
+use martian::time::{ utc_to_msd, MarsInstant };
+
+const ITERATIONS: usize = 1_000_000;
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn bench<F>(name: &str, mut f: F)
+    where F: FnMut()
+{
+    // Warm up
+    for _ in 0..ITERATIONS / 10 {
+        f();
+    }
+
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    let elapsed = start.elapsed();
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - allocations;
+
+    println!(
+        "{:<28} {:>8.1} ns/iter {:>12.0} rows/s {:>6} allocations",
+        name,
+        elapsed.as_nanos() as f64 / ITERATIONS as f64,
+        ITERATIONS as f64 / elapsed.as_secs_f64(),
+        allocations
+    );
+}
+
+fn main() {
+    bench("utc_to_msd", || {
+        black_box(utc_to_msd(black_box("2012-08-06T05:17:57.000")).unwrap());
+    });
+
+    bench("utc_to_msd offset", || {
+        black_box(utc_to_msd(black_box("2012-08-06T07:17:57.123456789+02:00")).unwrap());
+    });
+
+    bench("utc_to_msd leap second", || {
+        black_box(utc_to_msd(black_box("2016-12-31T23:59:60.5Z")).unwrap());
+    });
+
+    bench("utc_to_msd invalid", || {
+        black_box(utc_to_msd(black_box("2021-02-30T00:00:00Z")).unwrap_err());
+    });
+
+    bench("MarsInstant::from_utc", || {
+        black_box(MarsInstant::from_utc(black_box("2012-08-06T05:17:57Z")).unwrap());
+    });
+}
@@ -1,3 +1,13 @@
-/// Regex pattern for ISO8601 date format used in the library (YYYY-MM-DDTHH:MM:SS.sss).
-pub const ISO8601_REGEX: &str =
-    r"^(\d{1,})-(\d{1,2})-(\d{1,2})T(\d{1,2}):(\d{1,2}):(\d{1,2})(?:\.(\d{1,}))?.*?$";
+/// Regex pattern for ISO8601 / RFC3339 date format accepted by the library
+/// (YYYY-MM-DDTHH:MM:SS[.sss][Z|±HH:MM]). Nothing in the library uses it anymore.
+#[deprecated(since = "0.3.0", note = "validate input with `martian::time::EarthTime::from_utc`")]
+pub const ISO8601_REGEX: &str = concat!(
+    r"^(\d{4})-(\d{2})-(\d{2})[Tt ](\d{2}):(\d{2}):(\d{2})(?:[.,](\d+))?",
+    r"(?:[Zz]|([+-])(\d{2})(?::?(\d{2}))?)?$"
+);
+
+/// Astronomical unit in kilometres (IAU 2012).
+pub const AU_KM: f64 = 149597870.7;
+
+/// Speed of light in vacuum in kilometres per second.
+pub const SPEED_OF_LIGHT_KM_S: f64 = 299792.458;
@@ -17,6 +17,14 @@ pub enum DateError {
     #[error("Provided sol value is out of range")]
     SolValueOutOfRange,
 
+    /// Start of the date range is after its end.
+    #[error("Start of the date range is after its end")]
+    InvalidDateRange,
+
+    /// Date range is longer than the searched range allows.
+    #[error("Date range is longer than the searched range allows")]
+    DateRangeTooLong,
+
     /// Unable to convert to Utc date time.
     #[error("Unable to convert to Utc date time")]
     UtcConversionError,
@@ -1,9 +1,13 @@
-use crate::date::darian::constants::{ DARIAN_MONTH_LENGTHS, SOL_DIFFERENCE_BETWEEN_DARIAN_AND_MSD };
+use std::time::SystemTime;
+use crate::date::darian::constants::{
+    DARIAN_MONTH_LENGTHS,
+    DARIAN_YEAR_SOLS,
+    SOL_DIFFERENCE_BETWEEN_DARIAN_AND_MSD,
+};
 use crate::date::darian::errors::*;
 use crate::date::darian::structs::*;
 
-#[cfg(not(test))]
-use crate::time::msd_now;
+use crate::time::{ msd_to_utc, utc_to_msd, Clock, MarsInstant, MarsNow, SystemClock };
 
 // ------------------------------------------------------------------------------------------------
 /// Get current Darian Date on Mars.
@@ -22,30 +26,44 @@ use crate::time::msd_now;
 /// It may propagate errors from time modules:
 ///
 /// - `DateError::TimeError(TimeError::<UtcTimeUnavailable>)`
-
 pub fn darian_now() -> Result<DarianDate, DateError> {
-    let msd = get_msd_now()?;
-    Ok(msd_to_darian(msd)?)
-}
-
-#[cfg(not(test))]
-fn get_msd_now() -> Result<f64, DateError> {
-    Ok(msd_now()?)
+    darian_now_with_clock(&SystemClock)
 }
 
-#[cfg(test)]
-fn get_msd_now() -> Result<f64, DateError> {
-    // Sol for 2024-11-07T17:58:40.000
-    Ok(53626.0011)
+/// Get current Darian Date on Mars as read from the provided clock.
+///
+/// # Examples
+///
+/// ```
+/// use martian::date::darian_now_with_clock;
+/// use martian::time::FixedClock;
+///
+/// let clock = FixedClock::from_utc("2024-11-07T17:58:40Z").unwrap();
+/// let darian_date = darian_now_with_clock(&clock).unwrap();
+///
+/// assert_eq!((darian_date.year, darian_date.month), (220, 24));
+/// ```
+///
+/// # Errors
+///
+/// It may propagate errors from time modules:
+///
+/// - `DateError::TimeError(TimeError::<UtcTimeUnavailable>)`
+pub fn darian_now_with_clock<C>(clock: &C) -> Result<DarianDate, DateError>
+    where C: Clock + ?Sized
+{
+    MarsNow::now_with_clock(clock)?.darian()
 }
 
 #[cfg(test)]
 mod darian_now_tests {
     use super::*;
+    use crate::time::FixedClock;
 
     #[test]
     fn test_darian_now() {
-        let darian_date = darian_now().unwrap();
+        let clock = FixedClock::from_utc("2024-11-07T17:58:40.000").unwrap();
+        let darian_date = darian_now_with_clock(&clock).unwrap();
         let expected_darian_date = DarianDate::new(220, 24, 25.0);
 
         let year = darian_date.year;
@@ -72,6 +90,18 @@ mod darian_now_tests {
             sol,
             expected_darian_date.sol
         );
+
+        // System clock is past the pinned date
+        assert!(darian_now().unwrap().year >= expected_darian_date.year);
+    }
+
+    #[test]
+    fn test_mars_now_darian() {
+        let clock = FixedClock::from_utc("2024-11-07T17:58:40.000").unwrap();
+        let now = MarsNow::now_with_clock(&clock).unwrap();
+
+        assert_eq!(now.darian().unwrap(), msd_to_darian(now.msd).unwrap());
+        assert_eq!(now.darian().unwrap(), now.instant.darian().unwrap());
     }
 }
 
@@ -80,7 +110,8 @@ mod darian_now_tests {
 ///
 /// # Arguments
 ///
-/// * `msd` -  Martian Sol Date to be converted to Darian date.
+/// * `msd` -  Martian Sol Date to be converted to Darian date. MSDs before the Darian epoch in
+///   1609 give negative years, the same ones `darian_to_msd` accepts.
 ///
 /// # Examples
 ///
@@ -93,30 +124,55 @@ mod darian_now_tests {
 /// println!("Darian Date: {}", darian_date);
 /// ```
 ///
-
+/// # Errors
+///
+/// - `DateError::TimeError(TimeError::<InvalidArgumentError>)`: If the MSD is not finite.
+/// - `DateError::TimeError(TimeError::<TimeCalculationError>)`: If the MSD is outside the
+///   range of a hifitime `Epoch`.
 pub fn msd_to_darian(msd: f64) -> Result<DarianDate, DateError> {
+    instant_to_darian(MarsInstant::from_msd(msd)?)
+}
+
+/// Converts a `MarsInstant`, or anything convertible into one such as a hifitime `Epoch` or
+/// `SystemTime`, to a Darian date.
+///
+/// # Examples
+///
+/// ```
+/// use martian::date::instant_to_darian;
+/// use martian::time::MarsInstant;
+///
+/// let instant = MarsInstant::from_utc("2024-11-07T17:58:40Z").unwrap();
+/// println!("Darian Date: {}", instant_to_darian(instant).unwrap());
+/// ```
+///
+/// # Errors
+///
+/// - `DateError::MonthValueOutOfRange`: If the calculated month is out of range.
+pub fn instant_to_darian<I>(instant: I) -> Result<DarianDate, DateError>
+    where I: Into<MarsInstant>
+{
+    let msd = instant.into().msd();
+
     // Adjust the MSD to the Darian calendar starting point
     // Martian Sol Date starts with sol 0 on 1873-12-29 12:09 UTC
     // While Darian calendar starts with sol 1 on 1609-03-01 18:40:34 UTC
     let adjusted_msd = msd + SOL_DIFFERENCE_BETWEEN_DARIAN_AND_MSD - 1.0;
 
-    // Split into total sols and fractional part
-    let total_sols = adjusted_msd.floor() as u32;
+    // Split into total sols and fractional part, sols before the Darian epoch are negative
+    let total_sols = adjusted_msd.floor() as i64;
     let sol_fraction = adjusted_msd - adjusted_msd.floor();
 
-    let mut sols_remaining = total_sols;
-    let mut year = 0;
-
-    // Determine the year
-    loop {
-        let year_length = if is_darian_leap_year(year) { 669 } else { 668 };
-        if sols_remaining >= year_length {
-            sols_remaining -= year_length;
-            year += 1;
-        } else {
-            break;
-        }
+    // Estimate the year from the mean year length and correct it by the exact count of sols
+    let mut year = (total_sols as f64 / DARIAN_MEAN_YEAR_SOLS).floor() as i32;
+    while get_darian_sols_before_year(year) > total_sols {
+        year -= 1;
     }
+    while get_darian_sols_before_year(year + 1) <= total_sols {
+        year += 1;
+    }
+
+    let mut sols_remaining = (total_sols - get_darian_sols_before_year(year)) as u32;
 
     // Determine the month and sol
     let mut month = 1;
@@ -135,18 +191,77 @@ pub fn msd_to_darian(msd: f64) -> Result<DarianDate, DateError> {
     Ok(DarianDate::new(year, month, sol))
 }
 
+impl MarsInstant {
+    /// Constructs a new `MarsInstant` instance from a Darian date.
+    ///
+    /// # Errors
+    ///
+    /// - `DateError::MonthValueOutOfRange`: If the month is below 1 or above 24.
+    /// - `DateError::SolValueOutOfRange`: If the sol is negative or exceeds the length of the month.
+    pub fn from_darian(darian_date: &DarianDate) -> Result<Self, DateError> {
+        let msd = darian_to_msd(darian_date)?;
+        Ok(MarsInstant::from_msd(msd)?)
+    }
+
+    /// Returns the Darian calendar date.
+    ///
+    /// # Errors
+    ///
+    /// May propagate errors from `msd_to_darian`.
+    pub fn darian(&self) -> Result<DarianDate, DateError> {
+        instant_to_darian(*self)
+    }
+}
+
+impl MarsNow {
+    /// Returns the Darian calendar date of the snapshot.
+    ///
+    /// # Errors
+    ///
+    /// May propagate errors from `msd_to_darian`.
+    pub fn darian(&self) -> Result<DarianDate, DateError> {
+        msd_to_darian(self.msd)
+    }
+}
+
+impl TryFrom<SystemTime> for DarianDate {
+    type Error = DateError;
+
+    fn try_from(time: SystemTime) -> Result<Self, DateError> {
+        instant_to_darian(time)
+    }
+}
+
 // Determines if a given Martian year is a leap year in the Darian calendar
 fn is_darian_leap_year(year: i32) -> bool {
     if year % 100 == 0 {
-        if year % 500 == 0 { true } else { false }
+        year % 500 == 0
     } else {
         year % 2 != 0 || year % 10 == 0
     }
 }
 
+// Returns the number of sols from the start of year 0 to the start of a given Martian year,
+// negative for years before 0. Counts the leap years of each rule instead of walking the years.
+fn get_darian_sols_before_year(year: i32) -> i64 {
+    let year = year as i64;
+
+    // Multiples of a period in [0, year), or minus the multiples in [year, 0)
+    let multiples = |period: i64| -(-year).div_euclid(period);
+
+    // Odd years and years divisible by 10, except centuries not divisible by 500
+    let odd_years = year.div_euclid(2);
+    let leap_years = odd_years + multiples(10) - multiples(100) + multiples(500);
+
+    year * DARIAN_YEAR_SOLS as i64 + leap_years
+}
+
+// Mean length of a Darian year in sols, 592 of every 1000 years are leap years
+const DARIAN_MEAN_YEAR_SOLS: f64 = DARIAN_YEAR_SOLS as f64 + 0.592;
+
 // Returns the length of a given month in a specific Martian year
 fn get_darian_month_length(year: i32, month: u8) -> Result<u8, DateError> {
-    if month < 1 || month > 24 {
+    if !(1..=24).contains(&month) {
         return Err(DateError::MonthValueOutOfRange);
     }
 
@@ -190,3 +305,305 @@ mod msd_to_darian_tests {
         );
     }
 }
+
+// ------------------------------------------------------------------------------------------------
+/// Converts a given Darian date to MSD.
+///
+/// # Arguments
+///
+/// * `darian_date` - Darian date to be converted to Martian Sol Date. Sol value is counted from 0
+///   and may contain a fractional part, the same way `msd_to_darian` returns it.
+///
+/// # Examples
+///
+/// ```
+/// use martian::date::{ darian_to_msd, DarianDate };
+///
+/// let darian_date = DarianDate::new(220, 24, 25.0);
+///
+/// let msd = darian_to_msd(&darian_date).unwrap();
+/// println!("Mars Sol Date: {:.7}", msd);
+/// ```
+///
+/// # Errors
+///
+/// - `DateError::MonthValueOutOfRange`: If the month is below 1 or above 24.
+/// - `DateError::SolValueOutOfRange`: If the sol is negative or exceeds the length of the month.
+pub fn darian_to_msd(darian_date: &DarianDate) -> Result<f64, DateError> {
+    let month_length = get_darian_month_length(darian_date.year, darian_date.month)? as f64;
+    if !darian_date.sol.is_finite() || darian_date.sol < 0.0 || darian_date.sol >= month_length {
+        return Err(DateError::SolValueOutOfRange);
+    }
+
+    // Sols of all years between the Darian epoch and the given year
+    let mut total_sols = get_darian_sols_before_year(darian_date.year);
+
+    // Add sols of all months preceding the given month
+    for month in 1..darian_date.month {
+        total_sols += get_darian_month_length(darian_date.year, month)? as i64;
+    }
+
+    // Reverse the adjustment done in `msd_to_darian`
+    Ok((total_sols as f64) + darian_date.sol - SOL_DIFFERENCE_BETWEEN_DARIAN_AND_MSD + 1.0)
+}
+
+#[cfg(test)]
+mod darian_to_msd_tests {
+    use super::*;
+
+    // Number of sols in a given Martian year, walked year by year to check the closed form
+    fn get_darian_year_length(year: i32) -> u16 {
+        if is_darian_leap_year(year) { DARIAN_YEAR_SOLS + 1 } else { DARIAN_YEAR_SOLS }
+    }
+
+    #[test]
+    fn test_darian_to_msd() {
+        // "2024-11-07T17:58:40.000";
+        let msd = 53626.0011;
+        let darian_date = msd_to_darian(msd).unwrap();
+
+        let result = darian_to_msd(&darian_date).unwrap();
+
+        assert!((result - msd).abs() < 0.00001, "MSD: {} != {}", result, msd);
+    }
+
+    #[test]
+    fn test_darian_sols_before_year() {
+        let mut total_sols: i64 = 0;
+        for year in 0..2000 {
+            assert_eq!(get_darian_sols_before_year(year), total_sols, "Year: {}", year);
+            total_sols += get_darian_year_length(year) as i64;
+        }
+
+        let mut total_sols: i64 = 0;
+        for year in (-2000..0).rev() {
+            total_sols -= get_darian_year_length(year) as i64;
+            assert_eq!(get_darian_sols_before_year(year), total_sols, "Year: {}", year);
+        }
+    }
+
+    #[test]
+    fn test_darian_to_msd_distant_year() {
+        let darian_date = DarianDate::new(i32::MAX, 1, 0.0);
+        let result = darian_to_msd(&darian_date).unwrap();
+
+        assert!(result.is_finite() && result > 1.4e12, "MSD: {}", result);
+    }
+
+    #[test]
+    fn test_darian_to_msd_round_trip() {
+        for msd in [0.0, 1.5, 12345.678, 49269.25, 53626.0011, 60000.999] {
+            let darian_date = msd_to_darian(msd).unwrap();
+            let result = darian_to_msd(&darian_date).unwrap();
+
+            assert!((result - msd).abs() < 0.00001, "MSD: {} != {}", result, msd);
+        }
+    }
+
+    #[test]
+    fn test_darian_round_trip_before_epoch() {
+        // Darian year 0 began in 1609, earlier dates have negative years
+        for year in [-1, -5, -100, -1234] {
+            for (month, sol) in [(1, 1.0), (12, 0.25), (24, 26.5)] {
+                let msd = darian_to_msd(&DarianDate::new(year, month, sol)).unwrap();
+                let result = msd_to_darian(msd).unwrap();
+
+                assert_eq!((result.year, result.month), (year, month), "MSD: {}", msd);
+                assert!((result.sol - sol).abs() < 0.00001, "Sol: {} != {}", result.sol, sol);
+            }
+        }
+
+        let result = msd_to_darian(-SOL_DIFFERENCE_BETWEEN_DARIAN_AND_MSD + 0.5).unwrap();
+        assert_eq!((result.year, result.month), (-1, 24));
+    }
+
+    #[test]
+    fn test_darian_to_msd_invalid_month() {
+        let result = darian_to_msd(&DarianDate::new(220, 25, 0.0));
+        assert_eq!(result.unwrap_err(), DateError::MonthValueOutOfRange);
+
+        let result = darian_to_msd(&DarianDate::new(220, 0, 0.0));
+        assert_eq!(result.unwrap_err(), DateError::MonthValueOutOfRange);
+    }
+
+    #[test]
+    fn test_darian_to_msd_invalid_sol() {
+        // Month 6 (Kumbha) has 27 sols
+        let result = darian_to_msd(&DarianDate::new(220, 6, 27.0));
+        assert_eq!(result.unwrap_err(), DateError::SolValueOutOfRange);
+
+        let result = darian_to_msd(&DarianDate::new(220, 1, -0.5));
+        assert_eq!(result.unwrap_err(), DateError::SolValueOutOfRange);
+
+        // Month 24 has an extra sol only in leap years
+        let result = darian_to_msd(&DarianDate::new(222, 24, 27.5));
+        assert_eq!(result.unwrap_err(), DateError::SolValueOutOfRange);
+
+        assert!(darian_to_msd(&DarianDate::new(221, 24, 27.5)).is_ok());
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+/// Converts a given Darian date to UTC datetime. Returns an ISO8601 formatted datetime string.
+///
+/// # Examples
+///
+/// ```
+/// use martian::date::{ darian_to_utc, DarianDate };
+///
+/// let darian_date = DarianDate::new(220, 24, 25.0);
+///
+/// match darian_to_utc(&darian_date) {
+///     Ok(utc) => println!("UTC: {}", utc),
+///     Err(e) => eprintln!("Error calculating UTC: {}", e),
+/// }
+/// ```
+///
+/// # Errors
+///
+/// - `DateError::MonthValueOutOfRange`: If the month is below 1 or above 24.
+/// - `DateError::SolValueOutOfRange`: If the sol is negative or exceeds the length of the month.
+/// - `DateError::TimeError`: Propagated from `msd_to_utc`.
+pub fn darian_to_utc(darian_date: &DarianDate) -> Result<String, DateError> {
+    let msd = darian_to_msd(darian_date)?;
+    Ok(msd_to_utc(msd)?)
+}
+
+#[cfg(test)]
+mod darian_to_utc_tests {
+    use super::*;
+    use hifitime::Epoch;
+
+    #[test]
+    fn test_darian_to_utc() {
+        let date_time = "2024-11-07T17:58:40.000";
+        let darian_date = msd_to_darian(53626.0011).unwrap();
+
+        let result = darian_to_utc(&darian_date).unwrap();
+
+        let expected_epoch = Epoch::from_gregorian_str(date_time).unwrap();
+        let result_epoch = Epoch::from_gregorian_str(&result).unwrap();
+
+        let difference = (expected_epoch - result_epoch).abs().to_seconds();
+
+        assert!(difference <= 1.0, "Difference is more than 1 second: {} seconds", difference);
+    }
+
+    #[test]
+    fn test_darian_to_utc_invalid_month() {
+        let result = darian_to_utc(&DarianDate::new(220, 25, 0.0));
+        assert_eq!(result.unwrap_err(), DateError::MonthValueOutOfRange);
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+/// Converts UTC datetime to a Darian date. Requires an ISO8601 formatted datetime string as input.
+///
+/// # Examples
+///
+/// ```
+/// use martian::date::utc_to_darian;
+///
+/// let date_time = "2024-11-07T17:58:40.000";
+///
+/// match utc_to_darian(date_time) {
+///     Ok(darian_date) => println!("Darian Date: {}", darian_date),
+///     Err(e) => eprintln!("Error calculating Darian Date: {}", e),
+/// }
+/// ```
+///
+/// # Errors
+///
+/// May propagate errors from `utc_to_msd`:
+///
+/// - `DateError::TimeError(TimeError::<ISO8601FormatError>)`
+/// - `DateError::TimeError(TimeError::<InvalidDateTimeField>)`
+/// - `DateError::TimeError(TimeError::<TimeCalculationError>)`
+pub fn utc_to_darian(datetime: &str) -> Result<DarianDate, DateError> {
+    let msd = utc_to_msd(datetime)?;
+    msd_to_darian(msd)
+}
+
+#[cfg(test)]
+mod utc_to_darian_tests {
+    use super::*;
+    use crate::time::TimeError;
+
+    #[test]
+    fn test_utc_to_darian() {
+        let result = utc_to_darian("2024-11-07T17:58:40.000").unwrap();
+
+        assert_eq!(result.year, 220);
+        assert_eq!(result.month, 24);
+        assert!((result.sol - 25.0).abs() < 0.1, "Sol: {} != 25.0", result.sol);
+    }
+
+    #[test]
+    fn test_utc_to_darian_and_back() {
+        let date_time = "2012-08-06T05:17:57.000";
+
+        let darian_date = utc_to_darian(date_time).unwrap();
+        let result = darian_to_utc(&darian_date).unwrap();
+
+        let expected_epoch = hifitime::Epoch::from_gregorian_str(date_time).unwrap();
+        let result_epoch = hifitime::Epoch::from_gregorian_str(&result).unwrap();
+
+        let difference = (expected_epoch - result_epoch).abs().to_seconds();
+
+        assert!(difference <= 1.0, "Difference is more than 1 second: {} seconds", difference);
+    }
+
+    #[test]
+    fn test_utc_to_darian_invalid_date_format() {
+        let result = utc_to_darian("21-11108-06T05:17:57.000");
+
+        assert_eq!(result.unwrap_err(), DateError::TimeError(TimeError::ISO8601FormatError));
+    }
+}
+
+#[cfg(test)]
+mod mars_instant_darian_tests {
+    use super::*;
+    use crate::time::TimeError;
+
+    #[test]
+    fn test_mars_instant_darian() {
+        let instant = MarsInstant::from_utc("2024-11-07T17:58:40.000").unwrap();
+        let result = instant.darian().unwrap();
+
+        assert_eq!(result, msd_to_darian(instant.msd()).unwrap());
+    }
+
+    #[test]
+    fn test_instant_to_darian() {
+        let instant = MarsInstant::from_utc("2024-11-07T17:58:40Z").unwrap();
+        let result = instant_to_darian(instant).unwrap();
+
+        assert_eq!(result, msd_to_darian(instant.msd()).unwrap());
+        assert_eq!(instant_to_darian(instant.epoch()).unwrap(), result);
+
+        let result = msd_to_darian(f64::NAN);
+        assert_eq!(result.unwrap_err(), DateError::TimeError(TimeError::InvalidArgumentError));
+    }
+
+    #[test]
+    fn test_darian_from_system_time() {
+        let time = SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1731002320);
+        let result = DarianDate::try_from(time).unwrap();
+
+        assert_eq!(result, utc_to_darian("2024-11-07T17:58:40Z").unwrap());
+    }
+
+    #[test]
+    fn test_mars_instant_from_darian() {
+        let darian_date = DarianDate::new(220, 24, 25.5);
+        let instant = MarsInstant::from_darian(&darian_date).unwrap();
+        let result = instant.darian().unwrap();
+
+        assert_eq!((result.year, result.month), (220, 24));
+        assert!((result.sol - 25.5).abs() < 1.0e-6, "Sol: {}", result.sol);
+
+        let result = MarsInstant::from_darian(&DarianDate::new(220, 25, 0.0));
+        assert_eq!(result.unwrap_err(), DateError::MonthValueOutOfRange);
+    }
+}
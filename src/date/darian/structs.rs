@@ -18,12 +18,7 @@ impl DarianDate {
 impl fmt::Display for DarianDate {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let sol_int = self.sol.floor() as u8;
-        let sol_frac = self.sol - (sol_int as f64);
 
-        if sol_frac == 0.0 {
-            write!(f, "{}-{}-{:.5}", self.year, self.month, sol_int)
-        } else {
-            write!(f, "{}-{}-{:.5}", self.year, self.month, sol_int)
-        }
+        write!(f, "{}-{}-{}", self.year, self.month, sol_int)
     }
 }
@@ -1,5 +1,7 @@
 //! Module responsible for handling date related operations.
 
 mod darian;
+mod seasons;
 
 pub use darian::*;
+pub use seasons::*;
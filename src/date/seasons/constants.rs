@@ -0,0 +1,8 @@
+/// Approximate solar longitude (Ls) of Mars perihelion in degrees.
+pub const PERIHELION_LS: f64 = 251.0;
+
+/// Approximate solar longitude (Ls) of Mars aphelion in degrees.
+pub const APHELION_LS: f64 = 71.0;
+
+/// Longest range of sols searched by `orbital_events`, about 150 Mars years.
+pub const MAX_ORBITAL_EVENTS_SOLS: u32 = 100_000;
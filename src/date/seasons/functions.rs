@@ -0,0 +1,374 @@
+use crate::date::darian::{ msd_to_darian, DateError };
+use crate::date::seasons::constants::{ APHELION_LS, MAX_ORBITAL_EVENTS_SOLS, PERIHELION_LS };
+use crate::date::seasons::structs::*;
+use crate::time::{
+    mars_year_ls_to_msd,
+    mean_anomaly,
+    msd_to_ls,
+    msd_to_mars_year,
+    msd_to_utc,
+    utc_to_msd,
+    TimeError,
+};
+
+/// All orbital event kinds in the order they happen within a Mars Year.
+const ORBITAL_EVENT_KINDS: [OrbitalEventKind; 6] = [
+    OrbitalEventKind::NorthernSpringEquinox,
+    OrbitalEventKind::Aphelion,
+    OrbitalEventKind::NorthernSummerSolstice,
+    OrbitalEventKind::NorthernAutumnEquinox,
+    OrbitalEventKind::Perihelion,
+    OrbitalEventKind::NorthernWinterSolstice,
+];
+
+// ------------------------------------------------------------------------------------------------
+/// Get the season on Mars for a given Mars Sol Date (MSD) and hemisphere.
+///
+/// # Examples
+///
+/// ```
+/// use martian::date::{ msd_to_season, Hemisphere, Season };
+///
+/// let season = msd_to_season(53626.0011, Hemisphere::Northern).unwrap();
+/// assert_eq!(season, Season::Winter);
+/// ```
+///
+/// # Errors
+///
+/// - `DateError::TimeError(TimeError::<InvalidArgumentError>)`: If the MSD is not finite.
+pub fn msd_to_season(msd: f64, hemisphere: Hemisphere) -> Result<Season, DateError> {
+    let ls = msd_to_ls(msd)?;
+
+    // Southern hemisphere seasons are shifted by half a year
+    let ls = match hemisphere {
+        Hemisphere::Northern => ls,
+        Hemisphere::Southern => (ls + 180.0).rem_euclid(360.0),
+    };
+
+    Ok(
+        if ls < 90.0 {
+            Season::Spring
+        } else if ls < 180.0 {
+            Season::Summer
+        } else if ls < 270.0 {
+            Season::Autumn
+        } else {
+            Season::Winter
+        }
+    )
+}
+
+/// Get the season on Mars for a given UTC datetime and hemisphere. Requires an ISO8601 formatted
+/// datetime string as input.
+///
+/// # Examples
+///
+/// ```
+/// use martian::date::{ utc_to_season, Hemisphere, Season };
+///
+/// let season = utc_to_season("2024-11-07T17:58:40.000", Hemisphere::Southern).unwrap();
+/// assert_eq!(season, Season::Summer);
+/// ```
+///
+/// # Errors
+///
+/// May propagate errors from `utc_to_msd`:
+///
+/// - `DateError::TimeError(TimeError::<ISO8601FormatError>)`
+/// - `DateError::TimeError(TimeError::<InvalidDateTimeField>)`
+/// - `DateError::TimeError(TimeError::<TimeCalculationError>)`
+pub fn utc_to_season(datetime: &str, hemisphere: Hemisphere) -> Result<Season, DateError> {
+    let msd = utc_to_msd(datetime)?;
+    msd_to_season(msd, hemisphere)
+}
+
+#[cfg(test)]
+mod season_tests {
+    use super::*;
+
+    #[test]
+    fn test_msd_to_season() {
+        // Ls 0° to 90°
+        let msd = mars_year_ls_to_msd(37, 45.0).unwrap();
+        assert_eq!(msd_to_season(msd, Hemisphere::Northern).unwrap(), Season::Spring);
+        assert_eq!(msd_to_season(msd, Hemisphere::Southern).unwrap(), Season::Autumn);
+
+        // Ls 180° to 270°
+        let msd = mars_year_ls_to_msd(37, 200.0).unwrap();
+        assert_eq!(msd_to_season(msd, Hemisphere::Northern).unwrap(), Season::Autumn);
+        assert_eq!(msd_to_season(msd, Hemisphere::Southern).unwrap(), Season::Spring);
+    }
+
+    #[test]
+    fn test_utc_to_season() {
+        // Curiosity landed at Ls 150.7°
+        let date_time = "2012-08-06T05:17:57.000";
+
+        assert_eq!(utc_to_season(date_time, Hemisphere::Northern).unwrap(), Season::Summer);
+        assert_eq!(utc_to_season(date_time, Hemisphere::Southern).unwrap(), Season::Winter);
+    }
+
+    #[test]
+    fn test_season_invalid_msd() {
+        let result = msd_to_season(f64::NAN, Hemisphere::Northern);
+        assert_eq!(result.unwrap_err(), DateError::TimeError(TimeError::InvalidArgumentError));
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+/// List all orbital events (equinoxes, solstices, perihelion and aphelion) of Mars between two
+/// UTC datetimes, sorted chronologically. Requires ISO8601 formatted datetime strings as input.
+///
+/// # Examples
+///
+/// ```
+/// use martian::date::orbital_events;
+///
+/// let events = orbital_events("2024-01-01T00:00:00.000", "2026-01-01T00:00:00.000").unwrap();
+///
+/// for event in events {
+///     println!("{}: MSD {:.3}, Darian {}", event, event.msd, event.darian_date);
+/// }
+/// ```
+///
+/// # Errors
+///
+/// - `DateError::InvalidDateRange`: If the start date is after the end date.
+/// - `DateError::DateRangeTooLong`: If the dates are more than `MAX_ORBITAL_EVENTS_SOLS` sols
+///   apart.
+///
+/// May propagate errors from `utc_to_msd`:
+///
+/// - `DateError::TimeError(TimeError::<ISO8601FormatError>)`
+/// - `DateError::TimeError(TimeError::<InvalidDateTimeField>)`
+/// - `DateError::TimeError(TimeError::<TimeCalculationError>)`
+pub fn orbital_events(
+    start_datetime: &str,
+    end_datetime: &str
+) -> Result<Vec<OrbitalEvent>, DateError> {
+    let start_msd = utc_to_msd(start_datetime)?;
+    let end_msd = utc_to_msd(end_datetime)?;
+
+    orbital_events_msd(start_msd, end_msd)
+}
+
+/// List all orbital events (equinoxes, solstices, perihelion and aphelion) of Mars between two
+/// Mars Sol Dates (MSD), sorted chronologically.
+///
+/// # Examples
+///
+/// ```
+/// use martian::date::orbital_events_msd;
+///
+/// let events = orbital_events_msd(53000.0, 54000.0).unwrap();
+/// println!("Found {} events", events.len());
+/// ```
+///
+/// # Errors
+///
+/// - `DateError::InvalidDateRange`: If the start MSD is after the end MSD.
+/// - `DateError::DateRangeTooLong`: If the MSDs are more than `MAX_ORBITAL_EVENTS_SOLS` sols
+///   apart.
+/// - `DateError::TimeError(TimeError::<InvalidArgumentError>)`: If any MSD is not finite.
+pub fn orbital_events_msd(start_msd: f64, end_msd: f64) -> Result<Vec<OrbitalEvent>, DateError> {
+    if !start_msd.is_finite() || !end_msd.is_finite() {
+        return Err(DateError::TimeError(TimeError::InvalidArgumentError));
+    }
+    if start_msd > end_msd {
+        return Err(DateError::InvalidDateRange);
+    }
+    if end_msd - start_msd > MAX_ORBITAL_EVENTS_SOLS as f64 {
+        return Err(DateError::DateRangeTooLong);
+    }
+
+    let start_year = msd_to_mars_year(start_msd)?;
+    let end_year = msd_to_mars_year(end_msd)?;
+
+    let mut events = Vec::new();
+    for mars_year in start_year..=end_year {
+        for kind in ORBITAL_EVENT_KINDS {
+            let msd = orbital_event_msd(kind, mars_year)?;
+            if msd >= start_msd && msd <= end_msd {
+                events.push(build_orbital_event(kind, mars_year, msd)?);
+            }
+        }
+    }
+
+    events.sort_by(|a, b| a.msd.total_cmp(&b.msd));
+    Ok(events)
+}
+
+/// Find the first orbital event of a given kind after a given Mars Sol Date (MSD).
+///
+/// # Examples
+///
+/// ```
+/// use martian::date::{ next_orbital_event, OrbitalEventKind };
+///
+/// let event = next_orbital_event(OrbitalEventKind::Perihelion, 53626.0011).unwrap();
+/// println!("Next perihelion: {}", event.utc);
+/// ```
+///
+/// # Errors
+///
+/// - `DateError::TimeError(TimeError::<InvalidArgumentError>)`: If the MSD is not finite.
+/// - `DateError::TimeError(TimeError::<TimeCalculationError>)`: If the event could not be found.
+pub fn next_orbital_event(kind: OrbitalEventKind, msd: f64) -> Result<OrbitalEvent, DateError> {
+    let mars_year = msd_to_mars_year(msd)?;
+
+    for mars_year in mars_year..=mars_year + 1 {
+        let event_msd = orbital_event_msd(kind, mars_year)?;
+        if event_msd > msd {
+            return build_orbital_event(kind, mars_year, event_msd);
+        }
+    }
+
+    Err(DateError::TimeError(TimeError::TimeCalculationError))
+}
+
+// Returns MSD of the orbital event of a given kind within a given Mars Year
+fn orbital_event_msd(kind: OrbitalEventKind, mars_year: i32) -> Result<f64, DateError> {
+    Ok(match kind {
+        OrbitalEventKind::NorthernSpringEquinox => mars_year_ls_to_msd(mars_year, 0.0)?,
+        OrbitalEventKind::NorthernSummerSolstice => mars_year_ls_to_msd(mars_year, 90.0)?,
+        OrbitalEventKind::NorthernAutumnEquinox => mars_year_ls_to_msd(mars_year, 180.0)?,
+        OrbitalEventKind::NorthernWinterSolstice => mars_year_ls_to_msd(mars_year, 270.0)?,
+        OrbitalEventKind::Perihelion => {
+            let guess = mars_year_ls_to_msd(mars_year, PERIHELION_LS)?;
+            find_mean_anomaly_crossing(0.0, guess)?
+        }
+        OrbitalEventKind::Aphelion => {
+            let guess = mars_year_ls_to_msd(mars_year, APHELION_LS)?;
+            find_mean_anomaly_crossing(180.0, guess)?
+        }
+    })
+}
+
+// Newton iterations finding the MSD closest to the guess at which mean anomaly reaches the target
+fn find_mean_anomaly_crossing(target: f64, guess: f64) -> Result<f64, DateError> {
+    let mut msd = guess;
+
+    for _ in 0..20 {
+        let difference = (mean_anomaly(msd)? - target + 180.0).rem_euclid(360.0) - 180.0;
+        if difference.abs() < 1.0e-9 {
+            return Ok(msd);
+        }
+
+        let rate = mean_anomaly(msd + 0.5)? - mean_anomaly(msd - 0.5)?;
+        let rate = (rate + 180.0).rem_euclid(360.0) - 180.0;
+        msd -= difference / rate;
+    }
+
+    Err(DateError::TimeError(TimeError::TimeCalculationError))
+}
+
+fn build_orbital_event(
+    kind: OrbitalEventKind,
+    mars_year: i32,
+    msd: f64
+) -> Result<OrbitalEvent, DateError> {
+    Ok(OrbitalEvent {
+        kind,
+        mars_year,
+        msd,
+        utc: msd_to_utc(msd)?,
+        darian_date: msd_to_darian(msd)?,
+    })
+}
+
+#[cfg(test)]
+mod orbital_events_tests {
+    use super::*;
+    use crate::date::darian::darian_to_msd;
+
+    #[test]
+    fn test_orbital_events() {
+        let events = orbital_events("2024-01-01T00:00:00.000", "2026-01-01T00:00:00.000").unwrap();
+        let kinds: Vec<OrbitalEventKind> = events
+            .iter()
+            .map(|event| event.kind)
+            .collect();
+
+        assert_eq!(kinds, vec![
+            OrbitalEventKind::NorthernAutumnEquinox,
+            OrbitalEventKind::Perihelion,
+            OrbitalEventKind::NorthernWinterSolstice,
+            OrbitalEventKind::NorthernSpringEquinox,
+            OrbitalEventKind::Aphelion,
+            OrbitalEventKind::NorthernSummerSolstice,
+            OrbitalEventKind::NorthernAutumnEquinox
+        ]);
+
+        // Mars Year 38 started on 2024-11-12
+        let equinox = &events[3];
+        assert_eq!(equinox.mars_year, 38);
+        assert!(equinox.utc.starts_with("2024-11-12"), "UTC: {}", equinox.utc);
+        assert_eq!(equinox.darian_date, msd_to_darian(equinox.msd).unwrap());
+    }
+
+    #[test]
+    fn test_perihelion_and_aphelion_ls() {
+        let events = orbital_events_msd(53000.0, 53700.0).unwrap();
+
+        for event in events {
+            let ls = msd_to_ls(event.msd).unwrap();
+            match event.kind {
+                OrbitalEventKind::Perihelion => assert!((ls - 250.9).abs() < 0.5, "Ls: {}", ls),
+                OrbitalEventKind::Aphelion => assert!((ls - 70.9).abs() < 0.5, "Ls: {}", ls),
+                _ => {}
+            }
+        }
+    }
+
+    #[test]
+    fn test_next_orbital_event() {
+        let msd = 53626.0011;
+        let event = next_orbital_event(OrbitalEventKind::NorthernSpringEquinox, msd).unwrap();
+
+        assert_eq!(event.mars_year, 38);
+        assert!(event.msd > msd);
+        assert!(event.utc.starts_with("2024-11-12"), "UTC: {}", event.utc);
+
+        let event = next_orbital_event(OrbitalEventKind::Perihelion, msd).unwrap();
+        assert_eq!(event.mars_year, 38);
+    }
+
+    #[test]
+    fn test_orbital_events_before_darian_epoch() {
+        // MSD -100000 is in 1592, before Darian year 0 began in 1609
+        let events = orbital_events_msd(-1.0e5, -1.0e5 + 700.0).unwrap();
+        assert!(events.len() >= 6);
+
+        for event in events {
+            let darian_date = &event.darian_date;
+
+            assert!(darian_date.year < 0, "{}: {}", event, darian_date);
+            assert!(darian_date.sol >= 0.0, "{}: {}", event, darian_date);
+            assert!((darian_to_msd(darian_date).unwrap() - event.msd).abs() < 0.00001);
+        }
+    }
+
+    #[test]
+    fn test_orbital_events_invalid_range() {
+        let result = orbital_events_msd(54000.0, 53000.0);
+        assert_eq!(result.unwrap_err(), DateError::InvalidDateRange);
+    }
+
+    #[test]
+    fn test_orbital_events_range_too_long() {
+        let max_sols = MAX_ORBITAL_EVENTS_SOLS as f64;
+        assert!(orbital_events_msd(53000.0, 53000.0 + max_sols).is_ok());
+
+        let result = orbital_events_msd(53000.0, 53000.0 + max_sols + 1.0);
+        assert_eq!(result.unwrap_err(), DateError::DateRangeTooLong);
+
+        let result = orbital_events_msd(0.0, 1.0e7);
+        assert_eq!(result.unwrap_err(), DateError::DateRangeTooLong);
+
+        let result = orbital_events("2000-01-01T00:00:00Z", "2300-01-01T00:00:00Z");
+        assert_eq!(result.unwrap_err(), DateError::DateRangeTooLong);
+
+        let result = orbital_events_msd(f64::NAN, 53000.0);
+        assert_eq!(result.unwrap_err(), DateError::TimeError(TimeError::InvalidArgumentError));
+    }
+}
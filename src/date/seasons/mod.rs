@@ -0,0 +1,7 @@
+mod constants;
+mod functions;
+mod structs;
+
+pub use functions::*;
+pub use constants::*;
+pub use structs::*;
@@ -0,0 +1,82 @@
+use std::fmt;
+use crate::date::darian::DarianDate;
+
+// ------------------------------------------------------------------------------------------------
+/// Hemisphere of Mars, used to determine the local season.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Hemisphere {
+    Northern,
+    Southern,
+}
+
+// ------------------------------------------------------------------------------------------------
+/// Astronomical season on Mars, bounded by equinoxes and solstices.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Season {
+    Spring,
+    Summer,
+    Autumn,
+    Winter,
+}
+
+impl fmt::Display for Season {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Season::Spring => "Spring",
+            Season::Summer => "Summer",
+            Season::Autumn => "Autumn",
+            Season::Winter => "Winter",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+/// Kind of an orbital event of Mars. Equinoxes and solstices are named after the northern
+/// hemisphere seasons they start.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OrbitalEventKind {
+    /// Ls 0°
+    NorthernSpringEquinox,
+    /// Ls 90°
+    NorthernSummerSolstice,
+    /// Ls 180°
+    NorthernAutumnEquinox,
+    /// Ls 270°
+    NorthernWinterSolstice,
+    /// Closest point of Mars orbit to the Sun.
+    Perihelion,
+    /// Farthest point of Mars orbit from the Sun.
+    Aphelion,
+}
+
+impl fmt::Display for OrbitalEventKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            OrbitalEventKind::NorthernSpringEquinox => "Northern Spring Equinox",
+            OrbitalEventKind::NorthernSummerSolstice => "Northern Summer Solstice",
+            OrbitalEventKind::NorthernAutumnEquinox => "Northern Autumn Equinox",
+            OrbitalEventKind::NorthernWinterSolstice => "Northern Winter Solstice",
+            OrbitalEventKind::Perihelion => "Perihelion",
+            OrbitalEventKind::Aphelion => "Aphelion",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+/// Represents a single orbital event of Mars with its time in MSD, UTC and Darian calendar.
+#[derive(Debug, PartialEq)]
+pub struct OrbitalEvent {
+    pub kind: OrbitalEventKind,
+    pub mars_year: i32,
+    pub msd: f64,
+    pub utc: String,
+    pub darian_date: DarianDate,
+}
+
+impl fmt::Display for OrbitalEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} MY{} ({})", self.kind, self.mars_year, self.utc)
+    }
+}
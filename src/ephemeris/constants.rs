@@ -0,0 +1,31 @@
+use crate::ephemeris::structs::OrbitalElements;
+
+/// Mean orbital elements of the Earth-Moon barycenter, valid 1800 AD - 2050 AD.
+/// Values taken from JPL "Keplerian Elements for Approximate Positions of the Major Planets".
+pub const EARTH_ORBITAL_ELEMENTS: OrbitalElements = OrbitalElements {
+    semi_major_axis: [1.00000261, 0.00000562],
+    eccentricity: [0.01671123, -0.00004392],
+    inclination: [-0.00001531, -0.01294668],
+    mean_longitude: [100.46457166, 35999.37244981],
+    longitude_of_perihelion: [102.93768193, 0.32327364],
+    longitude_of_ascending_node: [0.0, 0.0],
+};
+
+/// Mean orbital elements of Mars, valid 1800 AD - 2050 AD.
+/// Values taken from JPL "Keplerian Elements for Approximate Positions of the Major Planets".
+pub const MARS_ORBITAL_ELEMENTS: OrbitalElements = OrbitalElements {
+    semi_major_axis: [1.52371034, 0.00001847],
+    eccentricity: [0.0933941, 0.00007882],
+    inclination: [1.84969142, -0.00813131],
+    mean_longitude: [-4.55343205, 19140.30268499],
+    longitude_of_perihelion: [-23.94362959, 0.44441088],
+    longitude_of_ascending_node: [49.55953891, -0.29257343],
+};
+
+/// Number of days in a Julian century.
+pub const DAYS_IN_JULIAN_CENTURY: f64 = 36525.0;
+
+/// Longest range of sols searched by `geometry_events`, about 150 Mars years. It bounds the work
+/// of a single search, not accuracy: the range may reach past 1800 AD - 2050 AD where the mean
+/// elements are extrapolated.
+pub const MAX_GEOMETRY_EVENTS_SOLS: u32 = 100_000;
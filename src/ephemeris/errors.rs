@@ -0,0 +1,23 @@
+use thiserror::Error;
+use crate::time::{ TimeError };
+
+// ------------------------------------------------------------------------------------------------
+/// Errors that can occur while performing ephemeris-related operations.
+#[derive(Error, Debug, PartialEq)]
+pub enum EphemerisError {
+    /// Propagated time error from the time module.
+    #[error("Time error occurred: {0}")]
+    TimeError(#[from] TimeError),
+
+    /// Start of the date range is after its end.
+    #[error("Start of the date range is after its end")]
+    InvalidDateRange,
+
+    /// Date range is longer than the searched range allows.
+    #[error("Date range is longer than the searched range allows")]
+    DateRangeTooLong,
+
+    /// Unable to provide angle threshold value outside of 0 to 90 degrees range.
+    #[error("Unable to provide angle threshold value outside of 0 to 90 degrees range")]
+    ThresholdOutOfRange,
+}
@@ -0,0 +1,693 @@
+use crate::{ AU_KM, SPEED_OF_LIGHT_KM_S };
+use crate::ephemeris::constants::{
+    DAYS_IN_JULIAN_CENTURY,
+    EARTH_ORBITAL_ELEMENTS,
+    MARS_ORBITAL_ELEMENTS,
+    MAX_GEOMETRY_EVENTS_SOLS,
+};
+use crate::ephemeris::errors::*;
+use crate::ephemeris::structs::*;
+use crate::time::{
+    msd_to_utc,
+    utc_to_msd,
+    TimeError,
+    JD_J2000,
+    JD_ON_SOL_ZERO,
+    SOL_IN_EARTH_DAYS,
+};
+use hifitime::Duration;
+
+// ------------------------------------------------------------------------------------------------
+/// Calculate the heliocentric position of a body described by mean orbital elements for a given
+/// Mars Sol Date (MSD).
+///
+/// # Examples
+///
+/// ```
+/// use martian::ephemeris::{ heliocentric_position, MARS_ORBITAL_ELEMENTS };
+///
+/// let position = heliocentric_position(&MARS_ORBITAL_ELEMENTS, 53626.0011).unwrap();
+/// println!("Mars position: {}", position);
+/// ```
+///
+/// # Note
+///
+/// The JPL mean elements are only fitted to 1800 AD - 2050 AD. Dates outside that window are
+/// extrapolated without an error and drift further from the true position the further out
+/// they are.
+///
+/// # Errors
+///
+/// - `EphemerisError::TimeError(TimeError::<InvalidArgumentError>)`: If the MSD is not finite.
+pub fn heliocentric_position(
+    elements: &OrbitalElements,
+    msd: f64
+) -> Result<HeliocentricPosition, EphemerisError> {
+    if !msd.is_finite() {
+        return Err(EphemerisError::TimeError(TimeError::InvalidArgumentError));
+    }
+
+    // Julian centuries since J2000.0, TT is used in place of TDB
+    let centuries = (msd * SOL_IN_EARTH_DAYS + JD_ON_SOL_ZERO - JD_J2000) / DAYS_IN_JULIAN_CENTURY;
+    let element = |value: [f64; 2]| value[0] + value[1] * centuries;
+
+    let semi_major_axis = element(elements.semi_major_axis);
+    let eccentricity = element(elements.eccentricity);
+    let inclination = element(elements.inclination).to_radians();
+    let mean_longitude = element(elements.mean_longitude);
+    let longitude_of_perihelion = element(elements.longitude_of_perihelion);
+    let node = element(elements.longitude_of_ascending_node);
+
+    let argument_of_perihelion = (longitude_of_perihelion - node).to_radians();
+    let mean_anomaly = (mean_longitude - longitude_of_perihelion).to_radians();
+    let node = node.to_radians();
+
+    let eccentric_anomaly = solve_kepler(mean_anomaly, eccentricity);
+
+    // Position in the orbital plane with x axis pointing to perihelion
+    let x_orbit = semi_major_axis * (eccentric_anomaly.cos() - eccentricity);
+    let y_orbit = semi_major_axis * (1.0 - eccentricity.powi(2)).sqrt() * eccentric_anomaly.sin();
+
+    // Rotate to the J2000.0 ecliptic frame
+    let (sin_w, cos_w) = argument_of_perihelion.sin_cos();
+    let (sin_n, cos_n) = node.sin_cos();
+    let (sin_i, cos_i) = inclination.sin_cos();
+
+    Ok(
+        HeliocentricPosition::new(
+            (cos_w * cos_n - sin_w * sin_n * cos_i) * x_orbit +
+                (-sin_w * cos_n - cos_w * sin_n * cos_i) * y_orbit,
+            (cos_w * sin_n + sin_w * cos_n * cos_i) * x_orbit +
+                (-sin_w * sin_n + cos_w * cos_n * cos_i) * y_orbit,
+            sin_w * sin_i * x_orbit + cos_w * sin_i * y_orbit
+        )
+    )
+}
+
+/// Heliocentric position of the Earth-Moon barycenter for a given Mars Sol Date (MSD). Valid
+/// 1800 AD - 2050 AD, see `heliocentric_position`.
+///
+/// # Errors
+///
+/// - `EphemerisError::TimeError(TimeError::<InvalidArgumentError>)`: If the MSD is not finite.
+pub fn earth_position(msd: f64) -> Result<HeliocentricPosition, EphemerisError> {
+    heliocentric_position(&EARTH_ORBITAL_ELEMENTS, msd)
+}
+
+/// Heliocentric position of Mars for a given Mars Sol Date (MSD). Valid 1800 AD - 2050 AD, see
+/// `heliocentric_position`.
+///
+/// # Errors
+///
+/// - `EphemerisError::TimeError(TimeError::<InvalidArgumentError>)`: If the MSD is not finite.
+pub fn mars_position(msd: f64) -> Result<HeliocentricPosition, EphemerisError> {
+    heliocentric_position(&MARS_ORBITAL_ELEMENTS, msd)
+}
+
+// Solves Kepler's equation M = E - e * sin(E) for the eccentric anomaly using Newton's method
+fn solve_kepler(mean_anomaly: f64, eccentricity: f64) -> f64 {
+    let mean_anomaly = (mean_anomaly + std::f64::consts::PI).rem_euclid(std::f64::consts::TAU) -
+        std::f64::consts::PI;
+    let mut eccentric_anomaly = mean_anomaly + eccentricity * mean_anomaly.sin();
+
+    for _ in 0..30 {
+        let delta =
+            (eccentric_anomaly - eccentricity * eccentric_anomaly.sin() - mean_anomaly) /
+            (1.0 - eccentricity * eccentric_anomaly.cos());
+        eccentric_anomaly -= delta;
+
+        if delta.abs() < 1.0e-12 {
+            break;
+        }
+    }
+
+    eccentric_anomaly
+}
+
+#[cfg(test)]
+mod heliocentric_position_tests {
+    use super::*;
+    use crate::time::heliocentric_distance;
+
+    #[test]
+    fn test_solve_kepler() {
+        for (mean_anomaly, eccentricity) in [(0.5, 0.0934), (3.0, 0.0934), (-2.0, 0.0167)] {
+            let result = solve_kepler(mean_anomaly, eccentricity);
+            let check = result - eccentricity * result.sin();
+
+            assert!((check - mean_anomaly).abs() < 1.0e-12, "M: {} != {}", check, mean_anomaly);
+        }
+    }
+
+    #[test]
+    fn test_earth_position() {
+        // Earth is closest to the Sun in early January
+        let msd = utc_to_msd("2000-01-03T12:00:00.000").unwrap();
+        let result = earth_position(msd).unwrap();
+
+        assert!((result.distance() - 0.98329).abs() < 0.0005, "r: {}", result.distance());
+        assert!(result.z.abs() < 1.0e-4, "z: {}", result.z);
+    }
+
+    #[test]
+    fn test_mars_position_matches_mars24_distance() {
+        for msd in [44795.9998, 49269.25, 53626.0011] {
+            let result = mars_position(msd).unwrap().distance();
+            let expected = heliocentric_distance(msd).unwrap();
+
+            assert!((result - expected).abs() < 0.001, "r: {} != {}", result, expected);
+        }
+    }
+
+    #[test]
+    fn test_heliocentric_position_invalid_msd() {
+        let result = mars_position(f64::NAN);
+        assert_eq!(result.unwrap_err(), EphemerisError::TimeError(TimeError::InvalidArgumentError));
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+/// Distance between Earth and Mars in astronomical units (AU) for a given Mars Sol Date (MSD).
+///
+/// # Examples
+///
+/// ```
+/// use martian::ephemeris::msd_to_earth_mars_distance;
+///
+/// let distance = msd_to_earth_mars_distance(53626.0011).unwrap();
+/// println!("Earth-Mars distance: {:.4} AU", distance);
+/// ```
+///
+/// # Note
+///
+/// Positions are only valid 1800 AD - 2050 AD, other dates are extrapolated. See
+/// `heliocentric_position`.
+///
+/// # Errors
+///
+/// - `EphemerisError::TimeError(TimeError::<InvalidArgumentError>)`: If the MSD is not finite.
+pub fn msd_to_earth_mars_distance(msd: f64) -> Result<f64, EphemerisError> {
+    Ok(earth_position(msd)?.distance_to(&mars_position(msd)?))
+}
+
+/// Distance between Earth and Mars in astronomical units (AU) at a given UTC datetime. Requires
+/// an ISO8601 formatted datetime string as input.
+///
+/// # Examples
+///
+/// ```
+/// use martian::ephemeris::earth_mars_distance;
+///
+/// let distance = earth_mars_distance("2003-08-27T09:51:00.000").unwrap();
+/// println!("Earth-Mars distance: {:.4} AU", distance);
+/// ```
+///
+/// # Note
+///
+/// Positions are only valid 1800 AD - 2050 AD, other dates are extrapolated. See
+/// `heliocentric_position`.
+///
+/// # Errors
+///
+/// May propagate errors from `utc_to_msd`:
+///
+/// - `EphemerisError::TimeError(TimeError::<ISO8601FormatError>)`
+/// - `EphemerisError::TimeError(TimeError::<InvalidDateTimeField>)`
+/// - `EphemerisError::TimeError(TimeError::<TimeCalculationError>)`
+pub fn earth_mars_distance(datetime: &str) -> Result<f64, EphemerisError> {
+    let msd = utc_to_msd(datetime)?;
+    msd_to_earth_mars_distance(msd)
+}
+
+/// One-way light time between Earth and Mars for a given Mars Sol Date (MSD). Uses the
+/// geometric distance at the given instant.
+///
+/// # Examples
+///
+/// ```
+/// use martian::ephemeris::msd_to_light_time;
+///
+/// let light_time = msd_to_light_time(53626.0011).unwrap();
+/// println!("One-way light time: {}", light_time);
+/// ```
+///
+/// # Note
+///
+/// Positions are only valid 1800 AD - 2050 AD, other dates are extrapolated. See
+/// `heliocentric_position`.
+///
+/// # Errors
+///
+/// - `EphemerisError::TimeError(TimeError::<InvalidArgumentError>)`: If the MSD is not finite.
+pub fn msd_to_light_time(msd: f64) -> Result<Duration, EphemerisError> {
+    let distance_km = msd_to_earth_mars_distance(msd)? * AU_KM;
+    Ok(Duration::from_seconds(distance_km / SPEED_OF_LIGHT_KM_S))
+}
+
+/// One-way light time between Earth and Mars at a given UTC datetime. Uses the geometric
+/// distance at the given instant. Requires an ISO8601 formatted datetime string as input.
+///
+/// # Examples
+///
+/// ```
+/// use martian::ephemeris::light_time;
+///
+/// match light_time("2024-11-07T17:58:40.000") {
+///     Ok(light_time) => println!("One-way light time: {:.1} s", light_time.to_seconds()),
+///     Err(e) => eprintln!("Error calculating light time: {}", e),
+/// }
+/// ```
+///
+/// # Note
+///
+/// Positions are only valid 1800 AD - 2050 AD, other dates are extrapolated. See
+/// `heliocentric_position`.
+///
+/// # Errors
+///
+/// May propagate errors from `utc_to_msd`:
+///
+/// - `EphemerisError::TimeError(TimeError::<ISO8601FormatError>)`
+/// - `EphemerisError::TimeError(TimeError::<InvalidDateTimeField>)`
+/// - `EphemerisError::TimeError(TimeError::<TimeCalculationError>)`
+pub fn light_time(datetime: &str) -> Result<Duration, EphemerisError> {
+    let msd = utc_to_msd(datetime)?;
+    msd_to_light_time(msd)
+}
+
+#[cfg(test)]
+mod light_time_tests {
+    use super::*;
+    use crate::time::DateTimeField;
+
+    #[test]
+    fn test_earth_mars_distance() {
+        // Closest approach of 2003: 0.372719 AU
+        let result = earth_mars_distance("2003-08-27T09:51:00.000").unwrap();
+        assert!((result - 0.372719).abs() < 0.001, "Distance: {}", result);
+
+        // Closest approach of 2018: 0.384957 AU
+        let result = earth_mars_distance("2018-07-31T07:50:00.000").unwrap();
+        assert!((result - 0.384957).abs() < 0.001, "Distance: {}", result);
+    }
+
+    #[test]
+    fn test_light_time() {
+        let result = light_time("2003-08-27T09:51:00.000").unwrap().to_seconds();
+        assert!((result - 186.0).abs() < 1.0, "Light time: {} s", result);
+
+        // Around solar conjunction light time exceeds 20 minutes
+        let result = light_time("2023-11-18T00:00:00.000").unwrap().to_seconds();
+        assert!(result > 20.0 * 60.0, "Light time: {} s", result);
+    }
+
+    #[test]
+    fn test_light_time_invalid_date() {
+        let result = light_time("2021-13-29T00:00:00.000");
+        let error = TimeError::InvalidDateTimeField(DateTimeField::Month);
+        assert_eq!(result.unwrap_err(), EphemerisError::TimeError(error));
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+/// Sun-Earth-Mars angle (solar elongation of Mars) in degrees (0 to 180) for a given Mars Sol
+/// Date (MSD). Close to 0° during solar conjunction and close to 180° during opposition.
+///
+/// # Examples
+///
+/// ```
+/// use martian::ephemeris::msd_to_solar_elongation;
+///
+/// let elongation = msd_to_solar_elongation(53626.0011).unwrap();
+/// println!("Sun-Earth-Mars angle: {:.2}°", elongation);
+/// ```
+///
+/// # Note
+///
+/// Positions are only valid 1800 AD - 2050 AD, other dates are extrapolated. See
+/// `heliocentric_position`.
+///
+/// # Errors
+///
+/// - `EphemerisError::TimeError(TimeError::<InvalidArgumentError>)`: If the MSD is not finite.
+pub fn msd_to_solar_elongation(msd: f64) -> Result<f64, EphemerisError> {
+    let earth = earth_position(msd)?;
+    let mars = mars_position(msd)?;
+
+    // Angle at the Earth between directions to the Sun and to Mars
+    let to_sun = [-earth.x, -earth.y, -earth.z];
+    let to_mars = [mars.x - earth.x, mars.y - earth.y, mars.z - earth.z];
+    let dot = to_sun[0] * to_mars[0] + to_sun[1] * to_mars[1] + to_sun[2] * to_mars[2];
+
+    let cos_angle = dot / (earth.distance() * earth.distance_to(&mars));
+    Ok(cos_angle.clamp(-1.0, 1.0).acos().to_degrees())
+}
+
+/// Sun-Earth-Mars angle (solar elongation of Mars) in degrees at a given UTC datetime. Requires
+/// an ISO8601 formatted datetime string as input.
+///
+/// # Examples
+///
+/// ```
+/// use martian::ephemeris::solar_elongation;
+///
+/// let elongation = solar_elongation("2023-11-18T00:00:00.000").unwrap();
+/// println!("Sun-Earth-Mars angle: {:.2}°", elongation);
+/// ```
+///
+/// # Note
+///
+/// Positions are only valid 1800 AD - 2050 AD, other dates are extrapolated. See
+/// `heliocentric_position`.
+///
+/// # Errors
+///
+/// May propagate errors from `utc_to_msd`:
+///
+/// - `EphemerisError::TimeError(TimeError::<ISO8601FormatError>)`
+/// - `EphemerisError::TimeError(TimeError::<InvalidDateTimeField>)`
+/// - `EphemerisError::TimeError(TimeError::<TimeCalculationError>)`
+pub fn solar_elongation(datetime: &str) -> Result<f64, EphemerisError> {
+    let msd = utc_to_msd(datetime)?;
+    msd_to_solar_elongation(msd)
+}
+
+/// List oppositions, solar conjunctions and closest approaches of Earth and Mars between two
+/// UTC datetimes, sorted chronologically by their peak. Requires ISO8601 formatted datetime
+/// strings as input.
+///
+/// # Arguments
+///
+/// * `start_datetime` - Start of the searched range.
+/// * `end_datetime` - End of the searched range.
+/// * `threshold` - Sun-Earth-Mars angle in degrees delimiting the windows. Solar conjunction
+///   window lasts while the angle is below the threshold, opposition window while it is above
+///   `180 - threshold`.
+///
+/// # Examples
+///
+/// ```
+/// use martian::ephemeris::{ geometry_events, GeometryEventKind };
+///
+/// let events = geometry_events("2023-01-01T00:00:00.000", "2024-01-01T00:00:00.000", 2.0).unwrap();
+///
+/// for event in events.iter().filter(|e| e.kind == GeometryEventKind::SolarConjunction) {
+///     println!("Command moratorium: {} - {}", event.start.utc, event.end.utc);
+/// }
+/// ```
+///
+/// # Note
+///
+/// The search range may reach past 1800 AD - 2050 AD, where the positions behind it are
+/// extrapolated. Event times found there are only rough estimates that get worse the further
+/// out they are.
+///
+/// # Errors
+///
+/// - `EphemerisError::InvalidDateRange`: If the start date is after the end date.
+/// - `EphemerisError::DateRangeTooLong`: If the dates are more than `MAX_GEOMETRY_EVENTS_SOLS`
+///   sols apart.
+/// - `EphemerisError::ThresholdOutOfRange`: If the threshold is outside of 0 to 90 degrees.
+///
+/// May propagate errors from `utc_to_msd`:
+///
+/// - `EphemerisError::TimeError(TimeError::<ISO8601FormatError>)`
+/// - `EphemerisError::TimeError(TimeError::<InvalidDateTimeField>)`
+/// - `EphemerisError::TimeError(TimeError::<TimeCalculationError>)`
+pub fn geometry_events(
+    start_datetime: &str,
+    end_datetime: &str,
+    threshold: f64
+) -> Result<Vec<GeometryEvent>, EphemerisError> {
+    let start_msd = utc_to_msd(start_datetime)?;
+    let end_msd = utc_to_msd(end_datetime)?;
+
+    geometry_events_msd(start_msd, end_msd, threshold)
+}
+
+/// List oppositions, solar conjunctions and closest approaches of Earth and Mars between two
+/// Mars Sol Dates (MSD), sorted chronologically by their peak. See `geometry_events` for the
+/// meaning of the threshold.
+///
+/// # Examples
+///
+/// ```
+/// use martian::ephemeris::geometry_events_msd;
+///
+/// let events = geometry_events_msd(53000.0, 54000.0, 3.0).unwrap();
+/// println!("Found {} events", events.len());
+/// ```
+///
+/// # Note
+///
+/// Event times outside 1800 AD - 2050 AD are extrapolated, see `geometry_events`.
+///
+/// # Errors
+///
+/// - `EphemerisError::InvalidDateRange`: If the start MSD is after the end MSD.
+/// - `EphemerisError::DateRangeTooLong`: If the MSDs are more than `MAX_GEOMETRY_EVENTS_SOLS`
+///   sols apart.
+/// - `EphemerisError::ThresholdOutOfRange`: If the threshold is outside of 0 to 90 degrees.
+/// - `EphemerisError::TimeError(TimeError::<InvalidArgumentError>)`: If any MSD is not finite.
+pub fn geometry_events_msd(
+    start_msd: f64,
+    end_msd: f64,
+    threshold: f64
+) -> Result<Vec<GeometryEvent>, EphemerisError> {
+    if !start_msd.is_finite() || !end_msd.is_finite() {
+        return Err(EphemerisError::TimeError(TimeError::InvalidArgumentError));
+    }
+    if start_msd > end_msd {
+        return Err(EphemerisError::InvalidDateRange);
+    }
+    if end_msd - start_msd > MAX_GEOMETRY_EVENTS_SOLS as f64 {
+        return Err(EphemerisError::DateRangeTooLong);
+    }
+    if !(threshold > 0.0 && threshold < 90.0) {
+        return Err(EphemerisError::ThresholdOutOfRange);
+    }
+
+    let elongation = msd_to_solar_elongation;
+    let distance = msd_to_earth_mars_distance;
+
+    let mut events = Vec::new();
+
+    // Sample once per sol, extrema are refined afterwards. Synodic period is ~760 sols so
+    // neighbouring extrema are never closer than a sampling step. Sols are counted with an
+    // integer, adding 1.0 to a large MSD may not advance it.
+    let first_msd = start_msd.floor() - 1.0;
+    let sols = (end_msd - first_msd).floor() as u32 + 1;
+    let mut samples = [elongation(first_msd)?, elongation(first_msd + 1.0)?];
+    let mut distances = [distance(first_msd)?, distance(first_msd + 1.0)?];
+
+    for sol in 0..sols {
+        let msd = first_msd + sol as f64;
+        let next_elongation = elongation(msd + 2.0)?;
+        let next_distance = distance(msd + 2.0)?;
+
+        if samples[1] < samples[0] && samples[1] <= next_elongation {
+            let peak = refine_extremum(msd, msd + 2.0, elongation)?;
+            if peak >= start_msd && peak <= end_msd {
+                let window = find_window(peak, |t| Ok(elongation(t)? <= threshold))?;
+                events.push(build_geometry_event(GeometryEventKind::SolarConjunction, window)?);
+            }
+        }
+
+        if samples[1] > samples[0] && samples[1] >= next_elongation {
+            let peak = refine_extremum(msd, msd + 2.0, |t| Ok(-elongation(t)?))?;
+            if peak >= start_msd && peak <= end_msd {
+                let window = find_window(peak, |t| Ok(elongation(t)? >= 180.0 - threshold))?;
+                events.push(build_geometry_event(GeometryEventKind::Opposition, window)?);
+            }
+        }
+
+        if distances[1] < distances[0] && distances[1] <= next_distance {
+            let peak = refine_extremum(msd, msd + 2.0, distance)?;
+            if peak >= start_msd && peak <= end_msd {
+                let window = (peak, peak, peak);
+                events.push(build_geometry_event(GeometryEventKind::ClosestApproach, window)?);
+            }
+        }
+
+        samples = [samples[1], next_elongation];
+        distances = [distances[1], next_distance];
+    }
+
+    events.sort_by(|a, b| a.peak.msd.total_cmp(&b.peak.msd));
+    Ok(events)
+}
+
+// Golden-section search for the minimum of a function within the given bracket
+fn refine_extremum<F>(mut low: f64, mut high: f64, function: F) -> Result<f64, EphemerisError>
+    where F: Fn(f64) -> Result<f64, EphemerisError>
+{
+    let ratio = (5.0_f64.sqrt() - 1.0) / 2.0;
+
+    while high - low > 1.0e-7 {
+        let left = high - ratio * (high - low);
+        let right = low + ratio * (high - low);
+
+        if function(left)? < function(right)? {
+            high = right;
+        } else {
+            low = left;
+        }
+    }
+
+    Ok((low + high) / 2.0)
+}
+
+// Finds the start and end of the window around the peak in which the condition holds,
+// returns the peak as both ends if the condition does not hold at the peak
+fn find_window<F>(peak: f64, condition: F) -> Result<(f64, f64, f64), EphemerisError>
+    where F: Fn(f64) -> Result<bool, EphemerisError>
+{
+    if !condition(peak)? {
+        return Ok((peak, peak, peak));
+    }
+
+    let start = find_window_edge(peak, -1.0, &condition)?;
+    let end = find_window_edge(peak, 1.0, &condition)?;
+
+    Ok((start, peak, end))
+}
+
+// Steps away from the peak until the condition stops holding, then bisects the edge
+fn find_window_edge<F>(peak: f64, direction: f64, condition: &F) -> Result<f64, EphemerisError>
+    where F: Fn(f64) -> Result<bool, EphemerisError>
+{
+    let mut inside = peak;
+    let mut outside = peak + direction;
+
+    while condition(outside)? {
+        inside = outside;
+        outside += direction;
+
+        // Windows never last longer than half of the synodic period
+        if (outside - peak).abs() > 400.0 {
+            return Err(EphemerisError::TimeError(TimeError::TimeCalculationError));
+        }
+    }
+
+    while (outside - inside).abs() > 1.0e-7 {
+        let middle = (inside + outside) / 2.0;
+        if condition(middle)? {
+            inside = middle;
+        } else {
+            outside = middle;
+        }
+    }
+
+    Ok((inside + outside) / 2.0)
+}
+
+fn build_geometry_event(
+    kind: GeometryEventKind,
+    (start, peak, end): (f64, f64, f64)
+) -> Result<GeometryEvent, EphemerisError> {
+    let event_time = |msd: f64| -> Result<EventTime, EphemerisError> {
+        Ok(EventTime { msd, utc: msd_to_utc(msd)? })
+    };
+
+    Ok(GeometryEvent {
+        kind,
+        start: event_time(start)?,
+        peak: event_time(peak)?,
+        end: event_time(end)?,
+        elongation: msd_to_solar_elongation(peak)?,
+        distance: msd_to_earth_mars_distance(peak)?,
+    })
+}
+
+#[cfg(test)]
+mod geometry_events_tests {
+    use super::*;
+
+    fn assert_peak_on(event: &GeometryEvent, kind: GeometryEventKind, date: &str) {
+        let expected = utc_to_msd(&format!("{}T12:00:00.000", date)).unwrap();
+
+        assert_eq!(event.kind, kind);
+        assert!(
+            (event.peak.msd - expected).abs() < 1.5,
+            "{} peak {} != {}",
+            kind,
+            event.peak.utc,
+            date
+        );
+    }
+
+    #[test]
+    fn test_solar_elongation() {
+        let result = solar_elongation("2020-10-13T23:00:00.000").unwrap();
+        assert!(result > 175.0, "Elongation: {}", result);
+
+        let result = solar_elongation("2023-11-18T00:00:00.000").unwrap();
+        assert!(result < 1.0, "Elongation: {}", result);
+    }
+
+    #[test]
+    fn test_geometry_events() {
+        let events = geometry_events("2020-01-01T00:00:00.000", "2024-01-01T00:00:00.000", 2.0);
+        let events = events.unwrap();
+
+        assert_eq!(events.len(), 6);
+        assert_peak_on(&events[0], GeometryEventKind::ClosestApproach, "2020-10-06");
+        assert_peak_on(&events[1], GeometryEventKind::Opposition, "2020-10-13");
+        assert_peak_on(&events[2], GeometryEventKind::SolarConjunction, "2021-10-08");
+        assert_peak_on(&events[3], GeometryEventKind::ClosestApproach, "2022-12-01");
+        assert_peak_on(&events[4], GeometryEventKind::Opposition, "2022-12-08");
+        assert_peak_on(&events[5], GeometryEventKind::SolarConjunction, "2023-11-18");
+    }
+
+    #[test]
+    fn test_solar_conjunction_window() {
+        let events = geometry_events("2023-10-01T00:00:00.000", "2023-12-31T00:00:00.000", 2.0);
+        let event = &events.unwrap()[0];
+
+        assert!(event.start.msd < event.peak.msd && event.peak.msd < event.end.msd);
+        assert!((msd_to_solar_elongation(event.start.msd).unwrap() - 2.0).abs() < 1.0e-4);
+        assert!((msd_to_solar_elongation(event.end.msd).unwrap() - 2.0).abs() < 1.0e-4);
+
+        // Wider threshold gives a longer moratorium
+        let events = geometry_events("2023-10-01T00:00:00.000", "2023-12-31T00:00:00.000", 5.0);
+        let wider = &events.unwrap()[0];
+
+        assert!(wider.end.msd - wider.start.msd > event.end.msd - event.start.msd);
+    }
+
+    #[test]
+    fn test_closest_approach_is_instantaneous() {
+        let events = geometry_events("2003-08-01T00:00:00.000", "2003-09-30T00:00:00.000", 2.0);
+        let events = events.unwrap();
+
+        let event = events
+            .iter()
+            .find(|event| event.kind == GeometryEventKind::ClosestApproach)
+            .unwrap();
+
+        assert_eq!(event.start, event.peak);
+        assert_eq!(event.end, event.peak);
+        assert!((event.distance - 0.372719).abs() < 0.001, "Distance: {}", event.distance);
+    }
+
+    #[test]
+    fn test_geometry_events_invalid_arguments() {
+        let result = geometry_events_msd(54000.0, 53000.0, 2.0);
+        assert_eq!(result.unwrap_err(), EphemerisError::InvalidDateRange);
+
+        let result = geometry_events_msd(53000.0, 54000.0, 0.0);
+        assert_eq!(result.unwrap_err(), EphemerisError::ThresholdOutOfRange);
+
+        let result = geometry_events_msd(f64::NAN, 54000.0, 2.0);
+        assert_eq!(result.unwrap_err(), EphemerisError::TimeError(TimeError::InvalidArgumentError));
+
+        let result = geometry_events_msd(0.0, 1.0e17, 2.0);
+        assert_eq!(result.unwrap_err(), EphemerisError::DateRangeTooLong);
+
+        let result = geometry_events_msd(-1.0e17, 1.0e17, 2.0);
+        assert_eq!(result.unwrap_err(), EphemerisError::DateRangeTooLong);
+
+        // Beyond 2^53 adding a sol to the MSD doesn't change it, the search still ends
+        assert!(geometry_events_msd(1.0e17, 1.0e17, 2.0).is_ok());
+    }
+}
@@ -0,0 +1,12 @@
+//! Module responsible for approximate positions of Earth and Mars and the geometry between them.
+//! Positions come from JPL mean orbital elements fitted to 1800 AD - 2050 AD.
+
+mod constants;
+mod functions;
+mod structs;
+mod errors;
+
+pub use functions::*;
+pub use constants::*;
+pub use structs::*;
+pub use errors::*;
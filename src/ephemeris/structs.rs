@@ -0,0 +1,109 @@
+use std::fmt;
+
+// ------------------------------------------------------------------------------------------------
+/// Keplerian orbital elements with their rates of change per Julian century from J2000.0.
+/// Each field holds `[value at J2000.0, rate per century]`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OrbitalElements {
+    /// Semi-major axis in AU.
+    pub semi_major_axis: [f64; 2],
+    /// Eccentricity.
+    pub eccentricity: [f64; 2],
+    /// Inclination to the ecliptic in degrees.
+    pub inclination: [f64; 2],
+    /// Mean longitude in degrees.
+    pub mean_longitude: [f64; 2],
+    /// Longitude of perihelion in degrees.
+    pub longitude_of_perihelion: [f64; 2],
+    /// Longitude of the ascending node in degrees.
+    pub longitude_of_ascending_node: [f64; 2],
+}
+
+// ------------------------------------------------------------------------------------------------
+/// Heliocentric position in the J2000.0 ecliptic frame, in astronomical units (AU).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HeliocentricPosition {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl HeliocentricPosition {
+    /// Constructs a new `HeliocentricPosition` instance with the provided coordinates in AU.
+    pub fn new(x: f64, y: f64, z: f64) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Distance from the Sun in AU.
+    pub fn distance(&self) -> f64 {
+        (self.x.powi(2) + self.y.powi(2) + self.z.powi(2)).sqrt()
+    }
+
+    /// Distance to another position in AU.
+    pub fn distance_to(&self, other: &HeliocentricPosition) -> f64 {
+        let dx = self.x - other.x;
+        let dy = self.y - other.y;
+        let dz = self.z - other.z;
+        (dx.powi(2) + dy.powi(2) + dz.powi(2)).sqrt()
+    }
+}
+
+impl fmt::Display for HeliocentricPosition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({:.6}, {:.6}, {:.6}) AU", self.x, self.y, self.z)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+/// Kind of an Earth-Mars geometry event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GeometryEventKind {
+    /// Mars opposite to the Sun in the sky of the Earth.
+    Opposition,
+    /// Mars behind the Sun as seen from the Earth.
+    SolarConjunction,
+    /// Minimum of the Earth-Mars distance.
+    ClosestApproach,
+}
+
+impl fmt::Display for GeometryEventKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            GeometryEventKind::Opposition => "Opposition",
+            GeometryEventKind::SolarConjunction => "Solar Conjunction",
+            GeometryEventKind::ClosestApproach => "Closest Approach",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+/// Represents an instant as both Mars Sol Date (MSD) and UTC datetime.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventTime {
+    pub msd: f64,
+    pub utc: String,
+}
+
+// ------------------------------------------------------------------------------------------------
+/// Represents an Earth-Mars geometry event. Start and end delimit the window in which the
+/// Sun-Earth-Mars angle is within the requested threshold of the event. Closest approach, and
+/// events for which the threshold is never reached, are instantaneous, so their start and end
+/// equal the peak.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeometryEvent {
+    pub kind: GeometryEventKind,
+    pub start: EventTime,
+    pub peak: EventTime,
+    pub end: EventTime,
+    /// Sun-Earth-Mars angle at the peak in degrees.
+    pub elongation: f64,
+    /// Earth-Mars distance at the peak in AU.
+    pub distance: f64,
+}
+
+impl fmt::Display for GeometryEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({} - {})", self.kind, self.start.utc, self.end.utc)
+    }
+}
@@ -8,3 +8,12 @@ pub mod time;
 
 #[cfg(feature = "date")]
 pub mod date;
+
+#[cfg(feature = "sun")]
+pub mod sun;
+
+#[cfg(feature = "ephemeris")]
+pub mod ephemeris;
+
+#[cfg(feature = "missions")]
+pub mod missions;
@@ -0,0 +1,115 @@
+use crate::missions::structs::{ Mission, SolNumbering };
+
+/// Viking 1 lander at Chryse Planitia.
+pub const VIKING_1: Mission = Mission {
+    name: "Viking 1",
+    landing_utc: "1976-07-20T11:53:06Z",
+    landing_msd: 36455.77791,
+    latitude: 22.27,
+    east_longitude: -47.95,
+    sol_numbering: SolNumbering::SolZero,
+};
+
+/// Viking 2 lander at Utopia Planitia.
+pub const VIKING_2: Mission = Mission {
+    name: "Viking 2",
+    landing_utc: "1976-09-03T22:37:50Z",
+    landing_msd: 36500.00966,
+    latitude: 47.64,
+    east_longitude: 134.29,
+    sol_numbering: SolNumbering::SolZero,
+};
+
+/// Mars Pathfinder at Ares Vallis.
+pub const PATHFINDER: Mission = Mission {
+    name: "Pathfinder",
+    landing_utc: "1997-07-04T16:56:55Z",
+    landing_msd: 43905.19527,
+    latitude: 19.13,
+    east_longitude: -33.22,
+    sol_numbering: SolNumbering::SolOne,
+};
+
+/// Spirit rover (MER-A) at Gusev crater.
+pub const SPIRIT: Mission = Mission {
+    name: "Spirit",
+    landing_utc: "2004-01-04T04:35:00Z",
+    landing_msd: 46216.14905,
+    latitude: -14.5684,
+    east_longitude: 175.4726,
+    sol_numbering: SolNumbering::SolOne,
+};
+
+/// Opportunity rover (MER-B) at Meridiani Planum.
+pub const OPPORTUNITY: Mission = Mission {
+    name: "Opportunity",
+    landing_utc: "2004-01-25T05:05:00Z",
+    landing_msd: 46236.60746,
+    latitude: -1.9462,
+    east_longitude: -5.5266,
+    sol_numbering: SolNumbering::SolOne,
+};
+
+/// Phoenix lander at Vastitas Borealis.
+pub const PHOENIX: Mission = Mission {
+    name: "Phoenix",
+    landing_utc: "2008-05-25T23:38:24Z",
+    landing_msd: 47777.03245,
+    latitude: 68.2188,
+    east_longitude: -125.7492,
+    sol_numbering: SolNumbering::SolZero,
+};
+
+/// Curiosity rover (MSL) at Gale crater.
+pub const CURIOSITY: Mission = Mission {
+    name: "Curiosity",
+    landing_utc: "2012-08-06T05:17:57Z",
+    landing_msd: 49269.24547,
+    latitude: -4.5895,
+    east_longitude: 137.4417,
+    sol_numbering: SolNumbering::SolZero,
+};
+
+/// InSight lander at Elysium Planitia.
+pub const INSIGHT: Mission = Mission {
+    name: "InSight",
+    landing_utc: "2018-11-26T19:44:52Z",
+    landing_msd: 51511.21302,
+    latitude: 4.5024,
+    east_longitude: 135.6234,
+    sol_numbering: SolNumbering::SolZero,
+};
+
+/// Perseverance rover (Mars 2020) at Jezero crater.
+pub const PERSEVERANCE: Mission = Mission {
+    name: "Perseverance",
+    landing_utc: "2021-02-18T20:43:38Z",
+    landing_msd: 52304.44684,
+    latitude: 18.4447,
+    east_longitude: 77.4508,
+    sol_numbering: SolNumbering::SolZero,
+};
+
+/// Zhurong rover (Tianwen-1) at Utopia Planitia.
+pub const ZHURONG: Mission = Mission {
+    name: "Zhurong",
+    landing_utc: "2021-05-14T23:18:00Z",
+    landing_msd: 52387.27694,
+    latitude: 25.066,
+    east_longitude: 109.925,
+    sol_numbering: SolNumbering::SolOne,
+};
+
+/// Registry of the built-in landed missions, ordered by landing date.
+pub const MISSIONS: [Mission; 10] = [
+    VIKING_1,
+    VIKING_2,
+    PATHFINDER,
+    SPIRIT,
+    OPPORTUNITY,
+    PHOENIX,
+    CURIOSITY,
+    INSIGHT,
+    PERSEVERANCE,
+    ZHURONG,
+];
@@ -0,0 +1,46 @@
+use thiserror::Error;
+use crate::time::{ TimeError };
+#[cfg(feature = "date")]
+use crate::date::{ DateError };
+
+// ------------------------------------------------------------------------------------------------
+/// Errors that can occur while performing mission-related operations.
+#[derive(Error, Debug, PartialEq)]
+pub enum MissionError {
+    /// Propagated time error from the time module.
+    #[error("Time error occurred: {0}")]
+    TimeError(#[from] TimeError),
+
+    /// Propagated date error from the date module.
+    #[cfg(feature = "date")]
+    #[error("Date error occurred: {0}")]
+    DateError(#[from] DateError),
+
+    /// No mission with the provided name is registered.
+    #[error("No mission with the provided name is registered")]
+    UnknownMission,
+
+    /// No site with the provided name is registered.
+    #[error("No site named '{0}' is registered")]
+    UnknownSite(String),
+
+    /// A site with the same name is already registered.
+    #[error("A site named '{0}' is already registered")]
+    DuplicateSite(String),
+
+    /// Site file is not valid JSON or TOML.
+    #[error("Unable to parse site file at line {line}")]
+    SiteParseError { line: usize },
+
+    /// Site file holds neither a list of sites nor a table with a `sites` list.
+    #[error("Site file has no list of sites")]
+    MissingSiteList,
+
+    /// Site entry is missing a required field.
+    #[error("Site entry {index} is missing the '{field}' field")]
+    MissingSiteField { index: usize, field: &'static str },
+
+    /// Site entry has a field with a wrong type or out of range value.
+    #[error("Site entry {index} has an invalid '{field}' field")]
+    InvalidSiteField { index: usize, field: &'static str },
+}
@@ -0,0 +1,588 @@
+use crate::missions::constants::MISSIONS;
+#[cfg(feature = "date")]
+use crate::date::{ msd_to_darian, DarianDate };
+#[cfg(feature = "date")]
+use crate::time::local_msd;
+use crate::missions::errors::*;
+use crate::missions::structs::*;
+use crate::time::{
+    local_sol,
+    lmst,
+    msd_to_utc,
+    mtc_to_msd,
+    normalize_longitude,
+    utc_to_msd,
+    Clock,
+    MarsNow,
+    SystemClock,
+    Time,
+};
+
+// ------------------------------------------------------------------------------------------------
+/// Look up a built-in landed mission by name, ignoring case.
+///
+/// # Examples
+///
+/// ```
+/// use martian::missions::{ mission, CURIOSITY };
+///
+/// assert_eq!(mission("curiosity").unwrap(), CURIOSITY);
+/// ```
+///
+/// # Errors
+///
+/// - `MissionError::UnknownMission`: If no built-in mission has the provided name.
+pub fn mission(name: &str) -> Result<Mission, MissionError> {
+    MISSIONS
+        .iter()
+        .find(|mission| mission.name.eq_ignore_ascii_case(name.trim()))
+        .copied()
+        .ok_or(MissionError::UnknownMission)
+}
+
+#[cfg(test)]
+mod mission_tests {
+    use super::*;
+    use crate::missions::constants::*;
+
+    #[test]
+    fn test_mission_lookup() {
+        assert_eq!(mission("Curiosity").unwrap(), CURIOSITY);
+        assert_eq!(mission(" PERSEVERANCE ").unwrap(), PERSEVERANCE);
+        assert_eq!(mission("viking 1").unwrap(), VIKING_1);
+        assert_eq!(mission("Beagle 2").unwrap_err(), MissionError::UnknownMission);
+    }
+
+    #[test]
+    fn test_missions_landing_msd() {
+        for mission in MISSIONS {
+            let msd = utc_to_msd(mission.landing_utc).unwrap();
+
+            assert!(
+                (msd - mission.landing_msd).abs() < 1.0e-5,
+                "{} landing MSD is off by {:.7}",
+                mission,
+                msd - mission.landing_msd
+            );
+        }
+    }
+
+    #[test]
+    fn test_missions_land_on_first_sol() {
+        for mission in MISSIONS {
+            let result = msd_to_mission_sol(&mission, mission.landing_msd).unwrap();
+            assert_eq!(result.sol, mission.sol_numbering.landing_sol(), "{}", mission);
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+/// Convert Mars Sol Date (MSD) to the mission sol and Local Mean Solar Time (LMST) at the landing
+/// site. Sols before the landing are negative or below the landing sol number.
+///
+/// # Examples
+///
+/// ```
+/// use martian::missions::{ msd_to_mission_sol, CURIOSITY };
+///
+/// let mission_sol = msd_to_mission_sol(&CURIOSITY, 53626.0011).unwrap();
+/// println!("Curiosity {}", mission_sol); // Curiosity Sol 4357 09:11:21:048
+/// ```
+///
+/// # Errors
+///
+/// - `MissionError::TimeError(TimeError::<InvalidArgumentError>)`: If the MSD is not finite or
+///   the mission longitude is out of range.
+pub fn msd_to_mission_sol(mission: &Mission, msd: f64) -> Result<MissionSol, MissionError> {
+    count_sol(mission.east_longitude, mission.landing_msd, mission.sol_numbering, msd)
+}
+
+// Sols are counted from the local sol of the epoch, shared by missions and sites
+fn count_sol(
+    east_longitude: f64,
+    epoch_msd: f64,
+    sol_numbering: SolNumbering,
+    msd: f64
+) -> Result<MissionSol, MissionError> {
+    let epoch_local_sol = local_sol(epoch_msd, east_longitude)?;
+    let sol = local_sol(msd, east_longitude)? - epoch_local_sol;
+
+    Ok(MissionSol {
+        sol: sol + sol_numbering.landing_sol(),
+        lmst: lmst(msd, east_longitude)?,
+    })
+}
+
+/// Convert UTC datetime to the mission sol and Local Mean Solar Time (LMST) at the landing site.
+/// Requires an ISO8601 formatted datetime string as input.
+///
+/// # Examples
+///
+/// ```
+/// use martian::missions::{ utc_to_mission_sol, OPPORTUNITY };
+///
+/// // Last contact with the Opportunity rover
+/// let mission_sol = utc_to_mission_sol(&OPPORTUNITY, "2018-06-10T12:00:00Z").unwrap();
+/// assert_eq!(mission_sol.sol, 5111);
+/// ```
+///
+/// # Errors
+///
+/// May propagate errors from `utc_to_msd`:
+///
+/// - `MissionError::TimeError(TimeError::<ISO8601FormatError>)`
+/// - `MissionError::TimeError(TimeError::<InvalidDateTimeField>)`
+/// - `MissionError::TimeError(TimeError::<TimeCalculationError>)`
+pub fn utc_to_mission_sol(mission: &Mission, datetime: &str) -> Result<MissionSol, MissionError> {
+    let msd = utc_to_msd(datetime)?;
+    msd_to_mission_sol(mission, msd)
+}
+
+/// Get the current mission sol and Local Mean Solar Time (LMST) at the landing site.
+///
+/// # Examples
+///
+/// ```
+/// use martian::missions::{ mission_sol_now, PERSEVERANCE };
+///
+/// match mission_sol_now(&PERSEVERANCE) {
+///     Ok(mission_sol) => println!("Perseverance {}", mission_sol),
+///     Err(e) => eprintln!("Error calculating mission sol: {}", e),
+/// }
+/// ```
+///
+/// # Errors
+///
+/// - `MissionError::TimeError(TimeError::<UtcTimeUnavailable>)`
+pub fn mission_sol_now(mission: &Mission) -> Result<MissionSol, MissionError> {
+    mission_sol_now_with_clock(&SystemClock, mission)
+}
+
+/// Get the mission sol and Local Mean Solar Time (LMST) at the landing site as read from the
+/// provided clock.
+///
+/// # Errors
+///
+/// - `MissionError::TimeError(TimeError::<UtcTimeUnavailable>)`
+pub fn mission_sol_now_with_clock<C>(
+    clock: &C,
+    mission: &Mission
+) -> Result<MissionSol, MissionError>
+    where C: Clock + ?Sized
+{
+    let now = MarsNow::now_with_clock(clock)?;
+    msd_to_mission_sol(mission, now.msd)
+}
+
+#[cfg(test)]
+mod msd_to_mission_sol_tests {
+    use super::*;
+    use crate::missions::constants::*;
+    use crate::time::{ FixedClock, TimeError };
+
+    #[test]
+    fn test_curiosity_sols() {
+        // Curiosity Rover Landing 2012-08-06T05:17:57.000, LMST 15:03
+        let result = utc_to_mission_sol(&CURIOSITY, "2012-08-06T05:17:57Z").unwrap();
+        assert_eq!(result.sol, 0);
+        assert_eq!((result.lmst.hours, result.lmst.minutes), (15, 3));
+
+        // Sol 1 starts at the first local midnight after landing
+        let result = msd_to_mission_sol(&CURIOSITY, 49270.0 - 137.4417 / 360.0).unwrap();
+        assert_eq!(result, MissionSol { sol: 1, lmst: Time::new(0, 0, 0, 0) });
+
+        // Sol 2000 began on 2018-03-22
+        let result = utc_to_mission_sol(&CURIOSITY, "2018-03-22T18:00:00Z").unwrap();
+        assert_eq!(result.sol, 2000);
+    }
+
+    #[test]
+    fn test_sol_one_missions() {
+        // Last contact with the Spirit and Opportunity rovers
+        let result = utc_to_mission_sol(&SPIRIT, "2010-03-22T12:00:00Z").unwrap();
+        assert_eq!(result.sol, 2210);
+
+        let result = utc_to_mission_sol(&OPPORTUNITY, "2018-06-10T12:00:00Z").unwrap();
+        assert_eq!(result.sol, 5111);
+    }
+
+    #[test]
+    fn test_before_landing() {
+        let result = msd_to_mission_sol(&PERSEVERANCE, PERSEVERANCE.landing_msd - 2.0).unwrap();
+        assert_eq!(result.sol, -2);
+
+        let result = msd_to_mission_sol(&ZHURONG, ZHURONG.landing_msd - 1.0).unwrap();
+        assert_eq!(result.sol, 0);
+    }
+
+    #[test]
+    fn test_mission_sol_now() {
+        let clock = FixedClock::from_utc(CURIOSITY.landing_utc).unwrap();
+        let result = mission_sol_now_with_clock(&clock, &CURIOSITY).unwrap();
+        assert_eq!(result.sol, 0);
+
+        let clock = FixedClock::from_utc("2024-11-07T17:58:40.000").unwrap();
+        let result = mission_sol_now_with_clock(&clock, &CURIOSITY).unwrap();
+        assert_eq!(result, utc_to_mission_sol(&CURIOSITY, "2024-11-07T17:58:40.000").unwrap());
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let result = msd_to_mission_sol(&CURIOSITY, f64::NAN);
+        assert_eq!(result.unwrap_err(), MissionError::TimeError(TimeError::InvalidArgumentError));
+
+        let result = utc_to_mission_sol(&CURIOSITY, "2012-08-06");
+        assert_eq!(result.unwrap_err(), MissionError::TimeError(TimeError::ISO8601FormatError));
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+/// Convert a mission sol and Local Mean Solar Time (LMST) at the landing site to Mars Sol Date
+/// (MSD).
+///
+/// # Examples
+///
+/// ```
+/// use martian::missions::{ mission_sol_to_msd, CURIOSITY };
+/// use martian::time::Time;
+///
+/// let msd = mission_sol_to_msd(&CURIOSITY, 4357, Time::new(12, 0, 0, 0)).unwrap();
+/// println!("Curiosity sol 4357 noon: MSD {:.5}", msd);
+/// ```
+///
+/// # Errors
+///
+/// - `MissionError::TimeError(TimeError::<InvalidArgumentError>)`: If any of the LMST components
+///   is out of range or the mission longitude is out of range.
+pub fn mission_sol_to_msd(mission: &Mission, sol: i64, lmst: Time) -> Result<f64, MissionError> {
+    sol_to_msd(mission.east_longitude, mission.landing_msd, mission.sol_numbering, sol, lmst)
+}
+
+// Inverse of `count_sol`
+fn sol_to_msd(
+    east_longitude: f64,
+    epoch_msd: f64,
+    sol_numbering: SolNumbering,
+    sol: i64,
+    lmst: Time
+) -> Result<f64, MissionError> {
+    let epoch_local_sol = local_sol(epoch_msd, east_longitude)?;
+    let local_sol = epoch_local_sol + sol - sol_numbering.landing_sol();
+
+    // LMST is MTC shifted by the longitude, so the sol and LMST form a local MSD
+    let local_msd = mtc_to_msd(local_sol, lmst)?;
+    Ok(local_msd - normalize_longitude(east_longitude) / 360.0)
+}
+
+/// Convert a mission sol and Local Mean Solar Time (LMST) at the landing site to UTC datetime.
+/// Returns an ISO8601 formatted datetime string.
+///
+/// # Examples
+///
+/// ```
+/// use martian::missions::{ mission_sol_to_utc, PERSEVERANCE };
+/// use martian::time::Time;
+///
+/// let utc = mission_sol_to_utc(&PERSEVERANCE, 100, Time::new(12, 0, 0, 0)).unwrap();
+/// println!("Perseverance sol 100 noon: {}", utc);
+/// ```
+///
+/// # Errors
+///
+/// - `MissionError::TimeError(TimeError::<InvalidArgumentError>)`: If any of the LMST components
+///   is out of range or the mission longitude is out of range.
+pub fn mission_sol_to_utc(mission: &Mission, sol: i64, lmst: Time) -> Result<String, MissionError> {
+    let msd = mission_sol_to_msd(mission, sol, lmst)?;
+    Ok(msd_to_utc(msd)?)
+}
+
+#[cfg(test)]
+mod mission_sol_to_msd_tests {
+    use super::*;
+    use crate::missions::constants::*;
+    use crate::time::TimeError;
+
+    #[test]
+    fn test_round_trip() {
+        for mission in MISSIONS {
+            for sol in [0, 1, 100, 1234] {
+                let lmst = Time::new(13, 45, 30, 250);
+                let msd = mission_sol_to_msd(&mission, sol, lmst).unwrap();
+                let result = msd_to_mission_sol(&mission, msd).unwrap();
+
+                assert_eq!(result, MissionSol { sol, lmst }, "{} sol {}", mission, sol);
+            }
+        }
+    }
+
+    #[test]
+    fn test_landing_sol() {
+        // Landing happened on the landing sol at the landing LMST
+        for mission in MISSIONS {
+            let landing = msd_to_mission_sol(&mission, mission.landing_msd).unwrap();
+            let msd = mission_sol_to_msd(&mission, landing.sol, landing.lmst).unwrap();
+
+            assert!((msd - mission.landing_msd).abs() < 1.0e-7, "{}", mission);
+        }
+    }
+
+    #[test]
+    fn test_mission_sol_to_utc() {
+        let utc = mission_sol_to_utc(&OPPORTUNITY, 5111, Time::new(12, 0, 0, 0)).unwrap();
+        assert!(utc.starts_with("2018-06-10"), "{}", utc);
+
+        let result = mission_sol_to_msd(&CURIOSITY, 10, Time::new(24, 0, 0, 0));
+        assert_eq!(result.unwrap_err(), MissionError::TimeError(TimeError::InvalidArgumentError));
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+/// Convert Mars Sol Date (MSD) to the site sol and Local Mean Solar Time (LMST) at a user-defined
+/// site.
+///
+/// # Examples
+///
+/// ```
+/// use martian::missions::{ msd_to_site_sol, Site, SolNumbering };
+///
+/// let site = Site {
+///     name: "Arcadia Base".to_string(),
+///     latitude: 46.0,
+///     east_longitude: -160.0,
+///     sol_epoch_msd: 54000.2,
+///     sol_numbering: SolNumbering::SolOne,
+/// };
+///
+/// let site_sol = msd_to_site_sol(&site, 54100.7).unwrap();
+/// println!("{} {}", site, site_sol);
+/// ```
+///
+/// # Errors
+///
+/// - `MissionError::TimeError(TimeError::<InvalidArgumentError>)`: If the MSD is not finite or
+///   the site longitude is out of range.
+pub fn msd_to_site_sol(site: &Site, msd: f64) -> Result<MissionSol, MissionError> {
+    count_sol(site.east_longitude, site.sol_epoch_msd, site.sol_numbering, msd)
+}
+
+/// Convert a site sol and Local Mean Solar Time (LMST) at a user-defined site to Mars Sol Date
+/// (MSD).
+///
+/// # Errors
+///
+/// - `MissionError::TimeError(TimeError::<InvalidArgumentError>)`: If any of the LMST components
+///   is out of range or the site longitude is out of range.
+pub fn site_sol_to_msd(site: &Site, sol: i64, lmst: Time) -> Result<f64, MissionError> {
+    sol_to_msd(site.east_longitude, site.sol_epoch_msd, site.sol_numbering, sol, lmst)
+}
+
+/// Convert Mars Sol Date (MSD) to the local Darian date at a user-defined site. The date changes
+/// at local mean midnight of the site.
+///
+/// # Errors
+///
+/// - `MissionError::TimeError(TimeError::<InvalidArgumentError>)`: If the MSD is not finite or
+///   the site longitude is out of range.
+#[cfg(feature = "date")]
+pub fn msd_to_site_darian(site: &Site, msd: f64) -> Result<DarianDate, MissionError> {
+    let local_msd = local_msd(msd, site.east_longitude)?;
+    Ok(msd_to_darian(local_msd)?)
+}
+
+#[cfg(test)]
+mod site_tests {
+    use super::*;
+    use crate::missions::constants::*;
+
+    const SITES_TOML: &str = r#"
+        # Simulated habitats
+        [[sites]]
+        name = "Hellas Habitat"
+        latitude = -42.4
+        east_longitude = 70.5
+        sol_epoch = 54000.25
+        first_sol = 1
+
+        [[sites]]
+        name = "Gale Replica"
+        latitude = -4.5895
+        east_longitude = 137.4417
+        sol_epoch = "2012-08-06T05:17:57Z" # Curiosity landing
+        first_sol = 0
+        notes = "ignored"
+    "#;
+
+    const SITES_JSON: &str = r#"{
+        "sites": [
+            {
+                "name": "Hellas Habitat",
+                "latitude": -42.4,
+                "east_longitude": 70.5,
+                "sol_epoch": 54000.25,
+                "first_sol": 1
+            },
+            {
+                "name": "Gale Replica",
+                "latitude": -4.5895,
+                "east_longitude": 137.4417,
+                "sol_epoch": "2012-08-06T05:17:57Z",
+                "first_sol": 0,
+                "notes": null
+            }
+        ]
+    }"#;
+
+    #[test]
+    fn test_load_sites() {
+        let from_toml = SiteRegistry::from_toml(SITES_TOML).unwrap();
+        let from_json = SiteRegistry::from_json(SITES_JSON).unwrap();
+        assert_eq!(from_toml, from_json);
+
+        let site = from_toml.get("HELLAS HABITAT").unwrap();
+        assert_eq!(site.east_longitude, 70.5);
+        assert_eq!(site.sol_numbering, SolNumbering::SolOne);
+
+        // Top level JSON list
+        let (_, list) = SITES_JSON.split_once('[').unwrap();
+        let (list, _) = list.rsplit_once(']').unwrap();
+        let json = format!("[{}]", list);
+        assert_eq!(SiteRegistry::from_json(&json).unwrap(), from_json);
+
+        // Native TOML date-time in place of the quoted one
+        let toml = SITES_TOML.replace("\"2012-08-06T05:17:57Z\"", "2012-08-06T05:17:57Z");
+        assert_eq!(SiteRegistry::from_toml(&toml).unwrap(), from_toml);
+    }
+
+    #[test]
+    fn test_site_queries() {
+        let registry = SiteRegistry::from_toml(SITES_TOML).unwrap();
+
+        // A site defined like a mission counts the same sols
+        let result = registry.utc_to_site_sol("gale replica", "2018-03-22T18:00:00Z").unwrap();
+        assert_eq!(result, utc_to_mission_sol(&CURIOSITY, "2018-03-22T18:00:00Z").unwrap());
+
+        // Sol epoch is on the first sol
+        let result = registry.site_sol("Hellas Habitat", 54000.25).unwrap();
+        assert_eq!(result.sol, 1);
+
+        let lmst = Time::new(6, 30, 0, 0);
+        let msd = registry.site_sol_to_msd("Hellas Habitat", 42, lmst).unwrap();
+        assert_eq!(registry.site_sol("Hellas Habitat", msd).unwrap(), MissionSol { sol: 42, lmst });
+
+        let result = registry.site_sol("Olympus Station", 54000.0);
+        assert_eq!(result.unwrap_err(), MissionError::UnknownSite("Olympus Station".to_string()));
+    }
+
+    #[cfg(feature = "date")]
+    #[test]
+    fn test_site_darian() {
+        use crate::time::TimeError;
+
+        let registry = SiteRegistry::from_toml(SITES_TOML).unwrap();
+
+        // Local midnight at 70.5°E is before midnight at the prime meridian
+        let msd = 54000.9;
+        let darian = registry.site_darian("Hellas Habitat", msd).unwrap();
+        assert_eq!(darian, msd_to_darian(msd + 70.5 / 360.0).unwrap());
+        assert_ne!(darian.sol, msd_to_darian(msd).unwrap().sol);
+
+        let result = registry.site_darian("Hellas Habitat", f64::NAN);
+        assert_eq!(result.unwrap_err(), MissionError::TimeError(TimeError::InvalidArgumentError));
+    }
+
+    #[test]
+    fn test_site_same_meridian() {
+        let site = |east_longitude: f64| Site {
+            name: "Tharsis Outpost".to_string(),
+            latitude: 0.0,
+            east_longitude,
+            sol_epoch_msd: 54000.25,
+            sol_numbering: SolNumbering::SolZero,
+        };
+        let (east, west) = (site(270.0), site(-90.0));
+        let lmst = Time::new(6, 30, 0, 0);
+
+        let result = msd_to_site_sol(&east, 54010.5).unwrap();
+        assert_eq!(result, msd_to_site_sol(&west, 54010.5).unwrap());
+
+        let result = site_sol_to_msd(&east, 10, lmst).unwrap();
+        assert_eq!(result, site_sol_to_msd(&west, 10, lmst).unwrap());
+
+        #[cfg(feature = "date")]
+        assert_eq!(
+            msd_to_site_darian(&east, 54010.5).unwrap(),
+            msd_to_site_darian(&west, 54010.5).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_invalid_site_entries() {
+        let entry = |fields: &str| format!("[{{\"name\": \"A\", \"first_sol\": 0, {}}}]", fields);
+        let cases = [
+            (
+                entry("\"latitude\": 0"),
+                MissionError::MissingSiteField { index: 0, field: "east_longitude" },
+            ),
+            (
+                entry("\"latitude\": 91, \"east_longitude\": 0, \"sol_epoch\": 0"),
+                MissionError::InvalidSiteField { index: 0, field: "latitude" },
+            ),
+            (
+                entry("\"latitude\": 0, \"east_longitude\": \"0\", \"sol_epoch\": 0"),
+                MissionError::InvalidSiteField { index: 0, field: "east_longitude" },
+            ),
+            (
+                entry("\"latitude\": 0, \"east_longitude\": 361, \"sol_epoch\": 0"),
+                MissionError::InvalidSiteField { index: 0, field: "east_longitude" },
+            ),
+            (
+                entry("\"latitude\": 0, \"east_longitude\": 0, \"sol_epoch\": \"2024-02-30\""),
+                MissionError::InvalidSiteField { index: 0, field: "sol_epoch" },
+            ),
+            ("[1]".to_string(), MissionError::InvalidSiteField { index: 0, field: "site" }),
+            ("{\"habitats\": []}".to_string(), MissionError::MissingSiteList),
+            ("{\"sites\": {}}".to_string(), MissionError::MissingSiteList),
+            ("\"sites\"".to_string(), MissionError::MissingSiteList),
+        ];
+
+        for (input, error) in cases {
+            assert_eq!(SiteRegistry::from_json(&input).unwrap_err(), error, "{}", input);
+        }
+
+        let toml = SITES_TOML.replace("first_sol = 0", "first_sol = 2");
+        let error = MissionError::InvalidSiteField { index: 1, field: "first_sol" };
+        assert_eq!(SiteRegistry::from_toml(&toml).unwrap_err(), error);
+
+        let toml = SITES_TOML.replace("name = \"Gale Replica\"", "name = \"  \"");
+        let error = MissionError::InvalidSiteField { index: 1, field: "name" };
+        assert_eq!(SiteRegistry::from_toml(&toml).unwrap_err(), error);
+    }
+
+    #[test]
+    fn test_duplicate_sites() {
+        let toml = SITES_TOML.replace("Gale Replica", "hellas habitat");
+        let result = SiteRegistry::from_toml(&toml);
+        assert_eq!(result.unwrap_err(), MissionError::DuplicateSite("hellas habitat".to_string()));
+
+        // Failed loads leave the registry unchanged
+        let mut registry = SiteRegistry::with_missions();
+        let toml = SITES_TOML.replace("Gale Replica", "Curiosity");
+        let result = registry.load_toml(&toml);
+        assert_eq!(result.unwrap_err(), MissionError::DuplicateSite("Curiosity".to_string()));
+        assert_eq!(registry.sites().len(), MISSIONS.len());
+
+        registry.load_toml(SITES_TOML).unwrap();
+        assert_eq!(registry.sites().len(), MISSIONS.len() + 2);
+        assert_eq!(registry.get("curiosity").unwrap(), &Site::from(CURIOSITY));
+
+        let mut site = Site::from(SPIRIT);
+        let result = registry.insert(site.clone());
+        assert_eq!(result.unwrap_err(), MissionError::DuplicateSite("Spirit".to_string()));
+
+        site.name = "Spirit Replica".to_string();
+        site.east_longitude = f64::INFINITY;
+        let result = registry.insert(site);
+        let error = MissionError::InvalidSiteField { index: 12, field: "east_longitude" };
+        assert_eq!(result.unwrap_err(), error);
+    }
+}
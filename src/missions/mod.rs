@@ -0,0 +1,12 @@
+//! Module responsible for handling landed mission sol counters and local times.
+
+mod constants;
+mod functions;
+mod structs;
+mod errors;
+mod parsers;
+
+pub use functions::*;
+pub use constants::*;
+pub use structs::*;
+pub use errors::*;
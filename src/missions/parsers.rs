@@ -0,0 +1,630 @@
+use std::iter::Peekable;
+use std::str::Chars;
+use crate::missions::errors::MissionError;
+use crate::missions::structs::{ Site, SolNumbering };
+use crate::time::utc_to_msd;
+
+// Site files are small, so they are read into a generic value tree before being checked
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Value {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Value>),
+    Table(Vec<(String, Value)>),
+}
+
+impl Value {
+    fn get(&self, key: &str) -> Option<&Value> {
+        match self {
+            Value::Table(entries) => entries.iter().find(|(name, _)| name == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Converts a parsed site file into sites. Accepts a top level list of sites or a table with a
+// `sites` list.
+pub(crate) fn sites_from_value(value: &Value) -> Result<Vec<Site>, MissionError> {
+    let entries = match value {
+        Value::Array(entries) => entries,
+        Value::Table(_) => match value.get("sites") {
+            Some(Value::Array(entries)) => entries,
+            _ => return Err(MissionError::MissingSiteList),
+        },
+        _ => return Err(MissionError::MissingSiteList),
+    };
+
+    entries
+        .iter()
+        .enumerate()
+        .map(|(index, entry)| site_from_value(index, entry))
+        .collect()
+}
+
+fn site_from_value(index: usize, entry: &Value) -> Result<Site, MissionError> {
+    if !matches!(entry, Value::Table(_)) {
+        return Err(MissionError::InvalidSiteField { index, field: "site" });
+    }
+
+    let field = |field: &'static str| {
+        entry.get(field).ok_or(MissionError::MissingSiteField { index, field })
+    };
+    let invalid = |field: &'static str| MissionError::InvalidSiteField { index, field };
+
+    let name = match field("name")? {
+        Value::String(name) => name.clone(),
+        _ => return Err(invalid("name")),
+    };
+    let latitude = match field("latitude")? {
+        Value::Number(latitude) => *latitude,
+        _ => return Err(invalid("latitude")),
+    };
+    let east_longitude = match field("east_longitude")? {
+        Value::Number(east_longitude) => *east_longitude,
+        _ => return Err(invalid("east_longitude")),
+    };
+
+    // Sol epoch is either an MSD or an ISO8601 formatted UTC datetime string
+    let sol_epoch_msd = match field("sol_epoch")? {
+        Value::Number(msd) => *msd,
+        Value::String(datetime) => utc_to_msd(datetime).map_err(|_| invalid("sol_epoch"))?,
+        _ => return Err(invalid("sol_epoch")),
+    };
+
+    let sol_numbering = match field("first_sol")? {
+        Value::Number(sol) if *sol == 0.0 => SolNumbering::SolZero,
+        Value::Number(sol) if *sol == 1.0 => SolNumbering::SolOne,
+        _ => return Err(invalid("first_sol")),
+    };
+
+    let site = Site { name, latitude, east_longitude, sol_epoch_msd, sol_numbering };
+    site.validate(index)?;
+
+    Ok(site)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Arrays and objects nested deeper than this are rejected, so hostile input can't overflow the
+// stack of the recursive parser. Site files only nest two levels deep.
+const MAX_JSON_DEPTH: usize = 128;
+
+// Parses a JSON document (RFC 8259)
+pub(crate) fn parse_json(input: &str) -> Result<Value, MissionError> {
+    let mut parser = JsonParser { chars: input.chars().peekable(), line: 1, depth: 0 };
+
+    let value = parser.parse_value()?;
+    parser.skip_whitespace();
+
+    match parser.chars.next() {
+        Some(_) => Err(parser.error()),
+        None => Ok(value),
+    }
+}
+
+struct JsonParser<'a> {
+    chars: Peekable<Chars<'a>>,
+    line: usize,
+    depth: usize,
+}
+
+impl JsonParser<'_> {
+    fn error(&self) -> MissionError {
+        MissionError::SiteParseError { line: self.line }
+    }
+
+    fn next(&mut self) -> Result<char, MissionError> {
+        let c = self.chars.next().ok_or_else(|| self.error())?;
+        if c == '\n' {
+            self.line += 1;
+        }
+        Ok(c)
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), MissionError> {
+        if self.chars.peek() != Some(&expected) {
+            return Err(self.error());
+        }
+
+        self.next().map(|_| ())
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(&c) = self.chars.peek() {
+            if !matches!(c, ' ' | '\t' | '\n' | '\r') {
+                break;
+            }
+            if c == '\n' {
+                self.line += 1;
+            }
+            self.chars.next();
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<Value, MissionError> {
+        self.skip_whitespace();
+
+        match self.chars.peek().copied().ok_or_else(|| self.error())? {
+            '{' => self.parse_nested(Self::parse_object),
+            '[' => self.parse_nested(Self::parse_array),
+            '"' => Ok(Value::String(self.parse_string()?)),
+            't' => self.parse_literal("true", Value::Bool(true)),
+            'f' => self.parse_literal("false", Value::Bool(false)),
+            'n' => self.parse_literal("null", Value::Null),
+            '-' | '0'..='9' => self.parse_number(),
+            _ => Err(self.error()),
+        }
+    }
+
+    fn parse_nested<F>(&mut self, parse: F) -> Result<Value, MissionError>
+        where F: FnOnce(&mut Self) -> Result<Value, MissionError>
+    {
+        if self.depth == MAX_JSON_DEPTH {
+            return Err(self.error());
+        }
+
+        self.depth += 1;
+        let value = parse(self);
+        self.depth -= 1;
+
+        value
+    }
+
+    fn parse_literal(&mut self, literal: &str, value: Value) -> Result<Value, MissionError> {
+        for expected in literal.chars() {
+            self.expect(expected)?;
+        }
+        Ok(value)
+    }
+
+    fn parse_number(&mut self) -> Result<Value, MissionError> {
+        let mut number = String::new();
+        while let Some(&c) = self.chars.peek() {
+            if !matches!(c, '-' | '+' | '.' | 'e' | 'E' | '0'..='9') {
+                break;
+            }
+            number.push(c);
+            self.chars.next();
+        }
+
+        if !is_valid_number(&number, false) {
+            return Err(self.error());
+        }
+
+        number.parse::<f64>().map(Value::Number).map_err(|_| self.error())
+    }
+
+    fn parse_string(&mut self) -> Result<String, MissionError> {
+        self.expect('"')?;
+
+        let mut string = String::new();
+        loop {
+            match self.next()? {
+                '"' => return Ok(string),
+                '\\' => {
+                    let c = match self.next()? {
+                        '"' => '"',
+                        '\\' => '\\',
+                        '/' => '/',
+                        'b' => '\u{8}',
+                        'f' => '\u{c}',
+                        'n' => '\n',
+                        'r' => '\r',
+                        't' => '\t',
+                        'u' => self.parse_unicode_escape()?,
+                        _ => return Err(self.error()),
+                    };
+                    string.push(c);
+                }
+                c if c.is_control() => return Err(self.error()),
+                c => string.push(c),
+            }
+        }
+    }
+
+    fn parse_unicode_escape(&mut self) -> Result<char, MissionError> {
+        let high = self.parse_hex()?;
+        if !(0xD800..0xDC00).contains(&high) {
+            return char::from_u32(high).ok_or_else(|| self.error());
+        }
+
+        // Characters outside the basic plane are escaped as a surrogate pair
+        self.expect('\\')?;
+        self.expect('u')?;
+        let low = self.parse_hex()?;
+        if !(0xDC00..0xE000).contains(&low) {
+            return Err(self.error());
+        }
+
+        let code = 0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00);
+        char::from_u32(code).ok_or_else(|| self.error())
+    }
+
+    fn parse_hex(&mut self) -> Result<u32, MissionError> {
+        let mut value = 0;
+        for _ in 0..4 {
+            let digit = self.next()?.to_digit(16).ok_or_else(|| self.error())?;
+            value = value * 16 + digit;
+        }
+        Ok(value)
+    }
+
+    fn parse_array(&mut self) -> Result<Value, MissionError> {
+        self.expect('[')?;
+        let mut values = Vec::new();
+
+        self.skip_whitespace();
+        if self.chars.peek() == Some(&']') {
+            self.chars.next();
+            return Ok(Value::Array(values));
+        }
+
+        loop {
+            values.push(self.parse_value()?);
+            self.skip_whitespace();
+
+            match self.next()? {
+                ',' => continue,
+                ']' => return Ok(Value::Array(values)),
+                _ => return Err(self.error()),
+            }
+        }
+    }
+
+    fn parse_object(&mut self) -> Result<Value, MissionError> {
+        self.expect('{')?;
+        let mut entries: Vec<(String, Value)> = Vec::new();
+
+        self.skip_whitespace();
+        if self.chars.peek() == Some(&'}') {
+            self.chars.next();
+            return Ok(Value::Table(entries));
+        }
+
+        loop {
+            self.skip_whitespace();
+            let key = self.parse_string()?;
+            if entries.iter().any(|(name, _)| *name == key) {
+                return Err(self.error());
+            }
+
+            self.skip_whitespace();
+            self.expect(':')?;
+            entries.push((key, self.parse_value()?));
+            self.skip_whitespace();
+
+            match self.next()? {
+                ',' => continue,
+                '}' => return Ok(Value::Table(entries)),
+                _ => return Err(self.error()),
+            }
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Parses the TOML subset used by site files: `[[sites]]` tables of `key = value` pairs with
+// string, number, boolean and date-time values, and `#` comments.
+pub(crate) fn parse_toml(input: &str) -> Result<Value, MissionError> {
+    let mut sites: Vec<Value> = Vec::new();
+
+    for (index, line) in input.lines().enumerate() {
+        let error = || MissionError::SiteParseError { line: index + 1 };
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if let Some(header) = line.strip_prefix("[[") {
+            let (name, rest) = header.split_once("]]").ok_or_else(error)?;
+            if name.trim() != "sites" || !is_blank_or_comment(rest) {
+                return Err(error());
+            }
+
+            sites.push(Value::Table(Vec::new()));
+            continue;
+        }
+
+        // Keys are only allowed inside a `[[sites]]` table
+        let Some(Value::Table(entries)) = sites.last_mut() else {
+            return Err(error());
+        };
+
+        let (key, value) = line.split_once('=').ok_or_else(error)?;
+        let key = parse_toml_key(key.trim()).ok_or_else(error)?;
+        let (value, rest) = parse_toml_value(value.trim_start()).ok_or_else(error)?;
+
+        if !is_blank_or_comment(rest) || entries.iter().any(|(name, _)| *name == key) {
+            return Err(error());
+        }
+
+        entries.push((key, value));
+    }
+
+    Ok(Value::Table(vec![("sites".to_string(), Value::Array(sites))]))
+}
+
+// Decimal number grammar of JSON, without leading zeros or bare dots. TOML also allows a
+// leading `+` and single underscores between digits.
+fn is_valid_number(number: &str, toml: bool) -> bool {
+    let unsigned = match number.strip_prefix('-') {
+        Some(unsigned) => unsigned,
+        None if toml => number.strip_prefix('+').unwrap_or(number),
+        None => number,
+    };
+    let (mantissa, exponent) = match unsigned.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => {
+            (mantissa, Some(exponent.strip_prefix(['+', '-']).unwrap_or(exponent)))
+        }
+        None => (unsigned, None),
+    };
+    let (integer, fraction) = match mantissa.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (mantissa, None),
+    };
+
+    let is_digits = |part: &str| {
+        !part.is_empty() &&
+            part.split('_').all(|group| !group.is_empty()) &&
+            part.chars().all(|c| c.is_ascii_digit() || (toml && c == '_'))
+    };
+
+    is_digits(integer) &&
+        (integer == "0" || !integer.starts_with('0')) &&
+        fraction.is_none_or(is_digits) &&
+        exponent.is_none_or(is_digits)
+}
+
+fn is_blank_or_comment(rest: &str) -> bool {
+    let rest = rest.trim_start();
+    rest.is_empty() || rest.starts_with('#')
+}
+
+fn parse_toml_key(key: &str) -> Option<String> {
+    if let Some(quoted) = key.strip_prefix('"') {
+        let (key, rest) = parse_toml_basic_string(quoted)?;
+        return rest.is_empty().then_some(key);
+    }
+
+    let bare = !key.is_empty() &&
+        key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+    bare.then(|| key.to_string())
+}
+
+// Returns the parsed value and the rest of the line
+fn parse_toml_value(input: &str) -> Option<(Value, &str)> {
+    if let Some(quoted) = input.strip_prefix('"') {
+        let (string, rest) = parse_toml_basic_string(quoted)?;
+        return Some((Value::String(string), rest));
+    }
+
+    if let Some(quoted) = input.strip_prefix('\'') {
+        let (string, rest) = quoted.split_once('\'')?;
+        return Some((Value::String(string.to_string()), rest));
+    }
+
+    let end = input.find(|c: char| c.is_whitespace() || c == '#').unwrap_or(input.len());
+    let (token, rest) = input.split_at(end);
+
+    if let Some(datetime) = parse_toml_datetime(token, rest) {
+        return Some(datetime);
+    }
+
+    let value = match token {
+        "true" => Value::Bool(true),
+        "false" => Value::Bool(false),
+        _ => {
+            if !is_valid_number(token, true) {
+                return None;
+            }
+            Value::Number(token.replace('_', "").parse::<f64>().ok()?)
+        }
+    };
+
+    Some((value, rest))
+}
+
+// Offset and local date-times are kept as strings, the same as a quoted ISO8601 datetime. TOML
+// allows a space in place of the `T`, which splits the value over two tokens.
+fn parse_toml_datetime<'a>(token: &str, rest: &'a str) -> Option<(Value, &'a str)> {
+    let is_date = token.len() >= 10 &&
+        token.bytes().take(10).enumerate().all(|(index, byte)| match index {
+            4 | 7 => byte == b'-',
+            _ => byte.is_ascii_digit(),
+        });
+    if !is_date {
+        return None;
+    }
+
+    let (datetime, rest) = if token.len() > 10 {
+        (token.to_string(), rest)
+    } else {
+        let time = rest.strip_prefix(' ')?;
+        let end = time.find(|c: char| c.is_whitespace() || c == '#').unwrap_or(time.len());
+        (format!("{} {}", token, &time[..end]), &time[end..])
+    };
+
+    // Local dates and times alone don't name an instant
+    utc_to_msd(&datetime).ok()?;
+    Some((Value::String(datetime), rest))
+}
+
+// Parses a basic string after the opening quote, returns it with the rest of the line
+fn parse_toml_basic_string(input: &str) -> Option<(String, &str)> {
+    let mut string = String::new();
+    let mut chars = input.char_indices();
+
+    while let Some((index, c)) = chars.next() {
+        match c {
+            '"' => return Some((string, &input[index + 1..])),
+            '\\' => {
+                let c = match chars.next()?.1 {
+                    '"' => '"',
+                    '\\' => '\\',
+                    'b' => '\u{8}',
+                    'f' => '\u{c}',
+                    'n' => '\n',
+                    'r' => '\r',
+                    't' => '\t',
+                    'u' => {
+                        let hex: String = chars.by_ref().take(4).map(|(_, c)| c).collect();
+                        if hex.len() != 4 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+                            return None;
+                        }
+                        char::from_u32(u32::from_str_radix(&hex, 16).ok()?)?
+                    }
+                    _ => return None,
+                };
+                string.push(c);
+            }
+            c => string.push(c),
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod parsers_tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_json() {
+        let input = r#" { "a": [1, -2.5e1, true, null], "b": "x\"\u00e9\ud83d\ude80" } "#;
+        let value = parse_json(input);
+
+        assert_eq!(
+            value.unwrap(),
+            Value::Table(vec![
+                (
+                    "a".to_string(),
+                    Value::Array(vec![
+                        Value::Number(1.0),
+                        Value::Number(-25.0),
+                        Value::Bool(true),
+                        Value::Null,
+                    ]),
+                ),
+                ("b".to_string(), Value::String("x\"é🚀".to_string())),
+            ])
+        );
+
+        for (input, number) in [("0", 0.0), ("-0.5", -0.5), ("10E+2", 1000.0), ("2e-0", 2.0)] {
+            assert_eq!(parse_json(input).unwrap(), Value::Number(number), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_parse_json_errors() {
+        let cases = [
+            ("{ \"a\": 1, }", 1),
+            ("[1, 2", 1),
+            ("{\n\"a\": 1,\n\"a\": 2\n}", 3),
+            ("{\n\"a\": tru\n}", 2),
+            ("[1] 2", 1),
+            ("", 1),
+            ("[01]", 1),
+            ("[1.]", 1),
+            ("[-.5]", 1),
+            ("[+1]", 1),
+            ("[1e]", 1),
+            ("[--1]", 1),
+        ];
+
+        for (input, line) in cases {
+            let error = MissionError::SiteParseError { line };
+            assert_eq!(parse_json(input).unwrap_err(), error, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_parse_json_nesting_limit() {
+        let nested = |depth: usize| format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+        assert!(parse_json(&nested(MAX_JSON_DEPTH)).is_ok());
+
+        let cases = [
+            (nested(MAX_JSON_DEPTH + 1), 1),
+            ("[".repeat(200_000), 1),
+            (format!("\n{}", "{\"a\": ".repeat(200_000)), 2),
+        ];
+
+        for (input, line) in cases {
+            let error = MissionError::SiteParseError { line };
+            assert_eq!(parse_json(&input).unwrap_err(), error);
+        }
+    }
+
+    #[test]
+    fn test_parse_toml() {
+        let input = r#"
+            # Habitats
+            [[sites]]
+            name = "Alpha \"Base\"" # comment
+            "east_longitude" = -1_000.5
+            sol = +1e1_0
+            literal = 'C:\path'
+            offset = 2030-01-01T00:00:00Z
+            local = 2030-01-01 00:00:00.5 # comment
+
+            [[ sites ]]
+            flag = true
+        "#;
+
+        assert_eq!(
+            parse_toml(input).unwrap(),
+            Value::Table(vec![
+                (
+                    "sites".to_string(),
+                    Value::Array(vec![
+                        Value::Table(vec![
+                            ("name".to_string(), Value::String("Alpha \"Base\"".to_string())),
+                            ("east_longitude".to_string(), Value::Number(-1000.5)),
+                            ("sol".to_string(), Value::Number(1.0e10)),
+                            ("literal".to_string(), Value::String("C:\\path".to_string())),
+                            (
+                                "offset".to_string(),
+                                Value::String("2030-01-01T00:00:00Z".to_string()),
+                            ),
+                            (
+                                "local".to_string(),
+                                Value::String("2030-01-01 00:00:00.5".to_string()),
+                            ),
+                        ]),
+                        Value::Table(vec![("flag".to_string(), Value::Bool(true))]),
+                    ]),
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_toml_errors() {
+        let cases = [
+            ("name = \"Alpha\"", 1),
+            ("[[sites]]\nname = \"Alpha", 2),
+            ("[[sites]]\nname = Alpha", 2),
+            ("[[sites]]\nname = 1 2", 2),
+            ("[[sites]]\na = 1\na = 2", 3),
+            ("[sites]", 1),
+            ("[[habitats]]", 1),
+            ("[[sites]]\nnot a key = 1", 2),
+            ("[[sites]]\nepoch = 2030-01-01", 2),
+            ("[[sites]]\nepoch = 00:00:00", 2),
+            ("[[sites]]\nepoch = 2030-02-30T00:00:00Z", 2),
+            ("[[sites]]\nname = \"\\u+041\"", 2),
+            ("[[sites]]\nname = \"\\u04\"", 2),
+            ("[[sites]]\nsol = 01", 2),
+            ("[[sites]]\nsol = 1.", 2),
+            ("[[sites]]\nsol = 1__0", 2),
+            ("[[sites]]\nsol = _1", 2),
+        ];
+
+        for (input, line) in cases {
+            let error = MissionError::SiteParseError { line };
+            assert_eq!(parse_toml(input).unwrap_err(), error, "{}", input);
+        }
+    }
+}
@@ -0,0 +1,329 @@
+use std::fmt;
+use crate::missions::errors::MissionError;
+use crate::missions::functions::{ msd_to_site_sol, site_sol_to_msd };
+use crate::missions::parsers::{ parse_json, parse_toml, sites_from_value };
+use crate::time::{ utc_to_msd, Time };
+#[cfg(feature = "date")]
+use crate::date::DarianDate;
+#[cfg(feature = "date")]
+use crate::missions::functions::msd_to_site_darian;
+
+// ------------------------------------------------------------------------------------------------
+/// Number given to the sol a mission landed on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SolNumbering {
+    /// Landing sol is sol 0, used by Viking, Phoenix, Curiosity, InSight and Perseverance.
+    SolZero,
+    /// Landing sol is sol 1, used by Pathfinder, Spirit, Opportunity and Zhurong.
+    SolOne,
+}
+
+impl SolNumbering {
+    /// Returns the number of the landing sol.
+    pub fn landing_sol(&self) -> i64 {
+        match self {
+            SolNumbering::SolZero => 0,
+            SolNumbering::SolOne => 1,
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+/// Landed mission with the data needed to count its sols. Mission sols change at local mean
+/// midnight of the landing site.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Mission {
+    /// Mission name.
+    pub name: &'static str,
+    /// ISO8601 formatted UTC datetime of the landing (spacecraft event time).
+    pub landing_utc: &'static str,
+    /// Mars Sol Date (MSD) of the landing.
+    pub landing_msd: f64,
+    /// Planetocentric latitude of the landing site in degrees.
+    pub latitude: f64,
+    /// Planetocentric east longitude of the landing site in degrees.
+    pub east_longitude: f64,
+    /// Number given to the landing sol.
+    pub sol_numbering: SolNumbering,
+}
+
+impl fmt::Display for Mission {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+/// Mission sol and Local Mean Solar Time (LMST) at the landing site.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MissionSol {
+    pub sol: i64,
+    pub lmst: Time,
+}
+
+impl fmt::Display for MissionSol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Sol {} {}", self.sol, self.lmst)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+/// Named site on Mars, such as a simulated habitat or a future landing site. Site sols are
+/// counted from the local sol of the sol epoch and change at local mean midnight.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Site {
+    /// Site name, unique within a `SiteRegistry` ignoring case.
+    pub name: String,
+    /// Planetocentric latitude of the site in degrees.
+    pub latitude: f64,
+    /// Planetocentric east longitude of the site in degrees.
+    pub east_longitude: f64,
+    /// Mars Sol Date (MSD) of the event that starts the sol count.
+    pub sol_epoch_msd: f64,
+    /// Number given to the sol of the epoch.
+    pub sol_numbering: SolNumbering,
+}
+
+impl Site {
+    // Checks the site fields, `index` is reported in the error
+    pub(crate) fn validate(&self, index: usize) -> Result<(), MissionError> {
+        let invalid = |field: &'static str| Err(MissionError::InvalidSiteField { index, field });
+
+        if self.name.trim().is_empty() {
+            return invalid("name");
+        }
+        if !self.latitude.is_finite() || self.latitude.abs() > 90.0 {
+            return invalid("latitude");
+        }
+        if !self.east_longitude.is_finite() || self.east_longitude.abs() > 360.0 {
+            return invalid("east_longitude");
+        }
+        if !self.sol_epoch_msd.is_finite() {
+            return invalid("sol_epoch");
+        }
+
+        Ok(())
+    }
+}
+
+impl From<Mission> for Site {
+    fn from(mission: Mission) -> Self {
+        Self {
+            name: mission.name.to_string(),
+            latitude: mission.latitude,
+            east_longitude: mission.east_longitude,
+            sol_epoch_msd: mission.landing_msd,
+            sol_numbering: mission.sol_numbering,
+        }
+    }
+}
+
+impl fmt::Display for Site {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+/// Registry of named sites loaded from JSON or TOML site files.
+///
+/// Site files list sites with the following fields, unknown fields are ignored:
+///
+/// - `name`: Site name.
+/// - `latitude`: Planetocentric latitude in degrees, between -90 and 90.
+/// - `east_longitude`: Planetocentric east longitude in degrees, between -360 and 360.
+/// - `sol_epoch`: Start of the sol count, either a Mars Sol Date (MSD) number or an ISO8601
+///   formatted UTC datetime string. TOML files may also use a native offset or local date-time,
+///   local ones are read as UTC.
+/// - `first_sol`: Number given to the sol of the epoch, `0` or `1`.
+///
+/// JSON files hold a list of sites, either at the top level or under a `sites` key. Any JSON
+/// document is accepted as long as arrays and objects nest at most 128 levels deep.
+///
+/// TOML files hold a `[[sites]]` table per site. Only the subset site files need is supported:
+/// bare and quoted keys, basic and literal strings, integers and floats in decimal notation,
+/// booleans, offset and local date-times, and `#` comments. Arrays, inline tables, multi-line
+/// strings, dotted keys, other tables, local dates and local times are rejected.
+///
+/// # Examples
+///
+/// ```
+/// use martian::missions::SiteRegistry;
+///
+/// let registry = SiteRegistry::from_toml(r#"
+///     [[sites]]
+///     name = "Hellas Habitat"
+///     latitude = -42.4
+///     east_longitude = 70.5
+///     sol_epoch = "2030-01-01T00:00:00Z"
+///     first_sol = 1
+/// "#).unwrap();
+///
+/// let site_sol = registry.site_sol("hellas habitat", 54000.5).unwrap();
+/// println!("Hellas Habitat {}", site_sol);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SiteRegistry {
+    sites: Vec<Site>,
+}
+
+impl SiteRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a registry holding the landing sites of the built-in missions.
+    pub fn with_missions() -> Self {
+        Self { sites: crate::missions::MISSIONS.into_iter().map(Site::from).collect() }
+    }
+
+    /// Creates a registry from a JSON site file.
+    ///
+    /// # Errors
+    ///
+    /// Same as `SiteRegistry::load_json`.
+    pub fn from_json(input: &str) -> Result<Self, MissionError> {
+        let mut registry = Self::new();
+        registry.load_json(input)?;
+        Ok(registry)
+    }
+
+    /// Creates a registry from a TOML site file.
+    ///
+    /// # Errors
+    ///
+    /// Same as `SiteRegistry::load_toml`.
+    pub fn from_toml(input: &str) -> Result<Self, MissionError> {
+        let mut registry = Self::new();
+        registry.load_toml(input)?;
+        Ok(registry)
+    }
+
+    /// Adds the sites of a JSON site file. The registry is left unchanged on error.
+    ///
+    /// # Errors
+    ///
+    /// - `MissionError::SiteParseError`: If the input is not valid JSON.
+    /// - `MissionError::MissingSiteList`: If the input holds no list of sites.
+    /// - `MissionError::MissingSiteField`: If a site entry is missing a required field.
+    /// - `MissionError::InvalidSiteField`: If a site field has a wrong type or is out of range.
+    /// - `MissionError::DuplicateSite`: If a site name is already taken.
+    pub fn load_json(&mut self, input: &str) -> Result<(), MissionError> {
+        let sites = sites_from_value(&parse_json(input)?)?;
+        self.extend(sites)
+    }
+
+    /// Adds the sites of a TOML site file. The registry is left unchanged on error.
+    ///
+    /// # Errors
+    ///
+    /// - `MissionError::SiteParseError`: If the input is not a valid site TOML file.
+    /// - `MissionError::MissingSiteField`: If a site entry is missing a required field.
+    /// - `MissionError::InvalidSiteField`: If a site field has a wrong type or is out of range.
+    /// - `MissionError::DuplicateSite`: If a site name is already taken.
+    pub fn load_toml(&mut self, input: &str) -> Result<(), MissionError> {
+        let sites = sites_from_value(&parse_toml(input)?)?;
+        self.extend(sites)
+    }
+
+    /// Adds a site to the registry.
+    ///
+    /// # Errors
+    ///
+    /// - `MissionError::InvalidSiteField`: If a site field is out of range, the index is the
+    ///   position the site would take in the registry.
+    /// - `MissionError::DuplicateSite`: If the site name is already taken.
+    pub fn insert(&mut self, site: Site) -> Result<(), MissionError> {
+        site.validate(self.sites.len())?;
+        self.extend(vec![site])
+    }
+
+    // Adds validated sites, checking names against the registry and each other
+    fn extend(&mut self, sites: Vec<Site>) -> Result<(), MissionError> {
+        for (index, site) in sites.iter().enumerate() {
+            let taken = self.get(&site.name).is_ok() ||
+                sites[..index].iter().any(|other| same_name(&other.name, &site.name));
+
+            if taken {
+                return Err(MissionError::DuplicateSite(site.name.clone()));
+            }
+        }
+
+        self.sites.extend(sites);
+        Ok(())
+    }
+
+    /// Returns all registered sites in insertion order.
+    pub fn sites(&self) -> &[Site] {
+        &self.sites
+    }
+
+    /// Look up a site by name, ignoring case.
+    ///
+    /// # Errors
+    ///
+    /// - `MissionError::UnknownSite`: If no site has the provided name.
+    pub fn get(&self, name: &str) -> Result<&Site, MissionError> {
+        self.sites
+            .iter()
+            .find(|site| same_name(&site.name, name))
+            .ok_or_else(|| MissionError::UnknownSite(name.to_string()))
+    }
+
+    /// Convert Mars Sol Date (MSD) to the site sol and Local Mean Solar Time (LMST) of the named
+    /// site.
+    ///
+    /// # Errors
+    ///
+    /// - `MissionError::UnknownSite`: If no site has the provided name.
+    /// - `MissionError::TimeError(TimeError::<InvalidArgumentError>)`: If the MSD is not finite.
+    pub fn site_sol(&self, name: &str, msd: f64) -> Result<MissionSol, MissionError> {
+        msd_to_site_sol(self.get(name)?, msd)
+    }
+
+    /// Convert UTC datetime to the site sol and Local Mean Solar Time (LMST) of the named site.
+    /// Requires an ISO8601 formatted datetime string as input.
+    ///
+    /// # Errors
+    ///
+    /// - `MissionError::UnknownSite`: If no site has the provided name.
+    ///
+    /// May propagate errors from `utc_to_msd`:
+    ///
+    /// - `MissionError::TimeError(TimeError::<ISO8601FormatError>)`
+    /// - `MissionError::TimeError(TimeError::<InvalidDateTimeField>)`
+    /// - `MissionError::TimeError(TimeError::<TimeCalculationError>)`
+    pub fn utc_to_site_sol(&self, name: &str, datetime: &str) -> Result<MissionSol, MissionError> {
+        let site = self.get(name)?;
+        msd_to_site_sol(site, utc_to_msd(datetime)?)
+    }
+
+    /// Convert a site sol and Local Mean Solar Time (LMST) of the named site to Mars Sol Date
+    /// (MSD).
+    ///
+    /// # Errors
+    ///
+    /// - `MissionError::UnknownSite`: If no site has the provided name.
+    /// - `MissionError::TimeError(TimeError::<InvalidArgumentError>)`: If any of the LMST
+    ///   components is out of range.
+    pub fn site_sol_to_msd(&self, name: &str, sol: i64, lmst: Time) -> Result<f64, MissionError> {
+        site_sol_to_msd(self.get(name)?, sol, lmst)
+    }
+
+    /// Convert Mars Sol Date (MSD) to the local Darian date of the named site. The date changes at
+    /// local mean midnight of the site.
+    ///
+    /// # Errors
+    ///
+    /// - `MissionError::UnknownSite`: If no site has the provided name.
+    /// - `MissionError::TimeError(TimeError::<InvalidArgumentError>)`: If the MSD is not finite.
+    #[cfg(feature = "date")]
+    pub fn site_darian(&self, name: &str, msd: f64) -> Result<DarianDate, MissionError> {
+        msd_to_site_darian(self.get(name)?, msd)
+    }
+}
+
+fn same_name(a: &str, b: &str) -> bool {
+    a.trim().eq_ignore_ascii_case(b.trim())
+}
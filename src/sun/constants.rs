@@ -0,0 +1,23 @@
+/// Apparent angular radius of the Sun seen from Mars at mean distance, in degrees.
+pub const SUN_APPARENT_RADIUS: f64 = 0.175;
+
+/// Solar elevation in degrees at which civil twilight begins or ends.
+pub const CIVIL_TWILIGHT_ELEVATION: f64 = -6.0;
+
+/// Solar elevation in degrees at which nautical twilight begins or ends.
+pub const NAUTICAL_TWILIGHT_ELEVATION: f64 = -12.0;
+
+/// Solar elevation in degrees at which astronomical twilight begins or ends.
+pub const ASTRONOMICAL_TWILIGHT_ELEVATION: f64 = -18.0;
+
+/// Mars equatorial radius in kilometres (IAU 2000).
+pub const MARS_EQUATORIAL_RADIUS_KM: f64 = 3396.19;
+
+/// Mars polar radius in kilometres (IAU 2000).
+pub const MARS_POLAR_RADIUS_KM: f64 = 3376.2;
+
+/// Total solar irradiance at 1 AU from the Sun in W/m² (Kopp & Lean, 2011).
+pub const SOLAR_CONSTANT: f64 = 1361.0;
+
+/// Nominal radius of the Sun in kilometres (IAU 2015).
+pub const SUN_RADIUS_KM: f64 = 695700.0;
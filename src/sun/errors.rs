@@ -0,0 +1,27 @@
+use thiserror::Error;
+use crate::time::{ TimeError };
+
+// ------------------------------------------------------------------------------------------------
+/// Errors that can occur while performing Sun-related operations.
+#[derive(Error, Debug, PartialEq)]
+pub enum SunError {
+    /// Propagated time error from the time module.
+    #[error("Time error occurred: {0}")]
+    TimeError(#[from] TimeError),
+
+    /// Unable to provide latitude value below -90 or above 90 degrees.
+    #[error("Unable to provide latitude value below -90 or above 90 degrees")]
+    LatitudeOutOfRange,
+
+    /// Unable to provide longitude value below -360 or above 360 degrees.
+    #[error("Unable to provide longitude value below -360 or above 360 degrees")]
+    LongitudeOutOfRange,
+
+    /// Dust optical depth must be a finite, non-negative value.
+    #[error("Dust optical depth must be a finite, non-negative value")]
+    OpticalDepthOutOfRange,
+
+    /// Unable to provide solar elevation threshold value below -90 or above 90 degrees.
+    #[error("Unable to provide solar elevation threshold value below -90 or above 90 degrees")]
+    ElevationOutOfRange,
+}
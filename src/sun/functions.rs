@@ -0,0 +1,847 @@
+use crate::AU_KM;
+use crate::sun::constants::{
+    MARS_EQUATORIAL_RADIUS_KM,
+    MARS_POLAR_RADIUS_KM,
+    SOLAR_CONSTANT,
+    SUN_RADIUS_KM,
+};
+use crate::sun::errors::*;
+use crate::sun::structs::*;
+use crate::time::{
+    equation_of_time,
+    heliocentric_distance,
+    local_sol,
+    lmst,
+    ltst,
+    msd_to_ls,
+    msd_to_utc,
+    normalize_longitude,
+    utc_to_msd,
+    SOL_IN_EARTH_DAYS,
+};
+
+// ------------------------------------------------------------------------------------------------
+/// Calculate sunrise and sunset of a given local sol at a Mars surface site.
+///
+/// # Arguments
+///
+/// * `sol` - Local sol number, as returned by `martian::time::local_sol`.
+/// * `latitude` - Planetographic latitude of the site in degrees (-90 to 90).
+/// * `east_longitude` - Planetocentric east longitude of the site in degrees (-360 to 360).
+/// * `threshold` - Solar elevation at which the Sun is considered to rise or set.
+///
+/// # Examples
+///
+/// ```
+/// use martian::sun::{ sun_times, SunElevationThreshold };
+///
+/// // Curiosity Rover at Gale crater
+/// let sun_times = sun_times(53626, -4.5895, 137.4417, SunElevationThreshold::Horizon).unwrap();
+///
+/// if let (Some(sunrise), Some(sunset)) = (sun_times.sunrise, sun_times.sunset) {
+///     println!("Sunrise: {}, Sunset: {}", sunrise, sunset);
+/// }
+/// ```
+///
+/// # Errors
+///
+/// - `SunError::LatitudeOutOfRange`: If the latitude is below -90 or above 90 degrees.
+/// - `SunError::ElevationOutOfRange`: If the threshold is below -90 or above 90 degrees.
+/// - `SunError::LongitudeOutOfRange`: If the longitude is below -360 or above 360 degrees.
+pub fn sun_times(
+    sol: i64,
+    latitude: f64,
+    east_longitude: f64,
+    threshold: SunElevationThreshold
+) -> Result<SunTimes, SunError> {
+    validate_latitude(latitude)?;
+    validate_longitude(east_longitude)?;
+
+    let elevation = threshold.degrees();
+    if !(-90.0..=90.0).contains(&elevation) {
+        return Err(SunError::ElevationOutOfRange);
+    }
+
+    // Start from local mean noon of the requested sol
+    let noon_msd = local_time_to_msd(sol, 12.0, east_longitude, 0.0);
+    let noon_half_arc = half_day_arc(noon_msd, latitude, elevation)?;
+
+    let (day_type, daylight_hours) = match noon_half_arc {
+        None if is_sun_above(noon_msd, latitude, elevation)? => (DayType::PolarDay, 24.0),
+        None => (DayType::PolarNight, 0.0),
+        Some(_) => {
+            let sunrise = find_crossing(sol, latitude, east_longitude, elevation, -1.0)?;
+            let sunset = find_crossing(sol, latitude, east_longitude, elevation, 1.0)?;
+
+            let daylight_hours = (sunset - sunrise) * 24.0;
+            let sunrise = build_solar_event(sunrise, east_longitude)?;
+            let sunset = build_solar_event(sunset, east_longitude)?;
+
+            return Ok(SunTimes {
+                sol,
+                day_type: DayType::Normal,
+                sunrise: Some(sunrise),
+                sunset: Some(sunset),
+                daylight_hours,
+            });
+        }
+    };
+
+    Ok(SunTimes {
+        sol,
+        day_type,
+        sunrise: None,
+        sunset: None,
+        daylight_hours,
+    })
+}
+
+/// Calculate sunrise and sunset at a Mars surface site for the local sol containing a given UTC
+/// datetime. Requires an ISO8601 formatted datetime string as input.
+///
+/// # Examples
+///
+/// ```
+/// use martian::sun::{ utc_to_sun_times, SunElevationThreshold };
+///
+/// let sun_times = utc_to_sun_times(
+///     "2024-11-07T17:58:40.000",
+///     18.4447,
+///     77.4509,
+///     SunElevationThreshold::CivilTwilight
+/// ).unwrap();
+///
+/// println!("Daylight: {:.2} Mars hours", sun_times.daylight_hours);
+/// ```
+///
+/// # Errors
+///
+/// - `SunError::LatitudeOutOfRange`: If the latitude is below -90 or above 90 degrees.
+/// - `SunError::ElevationOutOfRange`: If the threshold is below -90 or above 90 degrees.
+/// - `SunError::LongitudeOutOfRange`: If the longitude is below -360 or above 360 degrees.
+///
+/// May propagate errors from `utc_to_msd`:
+///
+/// - `SunError::TimeError(TimeError::<ISO8601FormatError>)`
+/// - `SunError::TimeError(TimeError::<InvalidDateTimeField>)`
+/// - `SunError::TimeError(TimeError::<TimeCalculationError>)`
+pub fn utc_to_sun_times(
+    datetime: &str,
+    latitude: f64,
+    east_longitude: f64,
+    threshold: SunElevationThreshold
+) -> Result<SunTimes, SunError> {
+    validate_longitude(east_longitude)?;
+
+    let msd = utc_to_msd(datetime)?;
+    let sol = local_sol(msd, east_longitude)?;
+
+    sun_times(sol, latitude, east_longitude, threshold)
+}
+
+// Iteratively finds MSD of the threshold crossing, -1.0 direction for sunrise and 1.0 for sunset.
+// Declination and equation of time change slowly during a sol, so a few iterations are enough.
+fn find_crossing(
+    sol: i64,
+    latitude: f64,
+    east_longitude: f64,
+    elevation: f64,
+    direction: f64
+) -> Result<f64, SunError> {
+    let mut msd = local_time_to_msd(sol, 12.0, east_longitude, 0.0);
+
+    for _ in 0..5 {
+        // Fall back to the noon arc if the Sun does not cross the threshold at the estimate
+        let half_arc = half_day_arc(msd, latitude, elevation)?.unwrap_or(0.0);
+        let ltst_hours = 12.0 + direction * half_arc / 15.0;
+        let eot = equation_of_time(msd)?;
+
+        msd = local_time_to_msd(sol, ltst_hours, east_longitude, eot);
+    }
+
+    Ok(msd)
+}
+
+// Converts local true solar time of a given local sol to MSD
+fn local_time_to_msd(sol: i64, ltst_hours: f64, east_longitude: f64, eot: f64) -> f64 {
+    let lmst_hours = ltst_hours - eot / 15.0;
+    (sol as f64) + lmst_hours / 24.0 - normalize_longitude(east_longitude) / 360.0
+}
+
+// Hour angle in degrees between local noon and the threshold crossing, `None` if the Sun
+// does not cross the threshold
+fn half_day_arc(msd: f64, latitude: f64, elevation: f64) -> Result<Option<f64>, SunError> {
+    let declination = solar_declination(msd)?.to_radians();
+    let latitude = latitude.to_radians();
+    let elevation = elevation.to_radians();
+
+    let cos_hour_angle =
+        (elevation.sin() - latitude.sin() * declination.sin()) /
+        (latitude.cos() * declination.cos());
+
+    if (-1.0..=1.0).contains(&cos_hour_angle) {
+        Ok(Some(cos_hour_angle.acos().to_degrees()))
+    } else {
+        Ok(None)
+    }
+}
+
+// Checks whether the Sun is above the elevation threshold at local noon
+fn is_sun_above(msd: f64, latitude: f64, elevation: f64) -> Result<bool, SunError> {
+    let declination = solar_declination(msd)?;
+    Ok(90.0 - (latitude - declination).abs() > elevation)
+}
+
+// Planetographic solar declination in degrees, step D-2 of the Mars24 algorithm
+fn solar_declination(msd: f64) -> Result<f64, SunError> {
+    let ls = msd_to_ls(msd)?.to_radians();
+    Ok((0.42565 * ls.sin()).asin().to_degrees() + 0.25 * ls.sin())
+}
+
+fn validate_latitude(latitude: f64) -> Result<(), SunError> {
+    if (-90.0..=90.0).contains(&latitude) {
+        Ok(())
+    } else {
+        Err(SunError::LatitudeOutOfRange)
+    }
+}
+
+fn validate_longitude(east_longitude: f64) -> Result<(), SunError> {
+    if (-360.0..=360.0).contains(&east_longitude) {
+        Ok(())
+    } else {
+        Err(SunError::LongitudeOutOfRange)
+    }
+}
+
+fn build_solar_event(msd: f64, east_longitude: f64) -> Result<SolarEvent, SunError> {
+    Ok(SolarEvent {
+        msd,
+        lmst: lmst(msd, east_longitude)?,
+        ltst: ltst(msd, east_longitude)?,
+        utc: msd_to_utc(msd)?,
+    })
+}
+
+#[cfg(test)]
+mod sun_times_tests {
+    use super::*;
+    use crate::time::{ mars_year_ls_to_msd, DateTimeField, TimeError };
+
+    #[test]
+    fn test_sun_times_at_equator_during_equinox() {
+        let msd = mars_year_ls_to_msd(37, 0.0).unwrap();
+        let sol = local_sol(msd, 0.0).unwrap();
+
+        let result = sun_times(sol, 0.0, 0.0, SunElevationThreshold::Custom(0.0)).unwrap();
+        let sunrise = result.sunrise.unwrap();
+        let sunset = result.sunset.unwrap();
+
+        assert_eq!(result.day_type, DayType::Normal);
+        assert!((result.daylight_hours - 12.0).abs() < 0.05, "Daylight: {}", result.daylight_hours);
+
+        // True solar time is symmetric around noon
+        assert_eq!((sunrise.ltst.hours, sunrise.ltst.minutes), (6, 0));
+        assert_eq!((sunset.ltst.hours, sunset.ltst.minutes), (18, 0));
+        assert!(sunrise.msd < sunset.msd);
+    }
+
+    #[test]
+    fn test_sun_times_event_consistency() {
+        let result = sun_times(53626, -4.5895, 137.4417, SunElevationThreshold::Horizon).unwrap();
+        let sunrise = result.sunrise.unwrap();
+
+        assert_eq!(local_sol(sunrise.msd, 137.4417).unwrap(), 53626);
+        assert_eq!(sunrise.lmst, lmst(sunrise.msd, 137.4417).unwrap());
+        assert_eq!(sunrise.utc, msd_to_utc(sunrise.msd).unwrap());
+    }
+
+    #[test]
+    fn test_twilight_extends_the_day() {
+        let (latitude, east_longitude) = (18.4447, 77.4509);
+
+        let horizon = sun_times(53626, latitude, east_longitude, SunElevationThreshold::Horizon);
+        let civil = sun_times(53626, latitude, east_longitude, SunElevationThreshold::CivilTwilight);
+        let astronomical = sun_times(
+            53626,
+            latitude,
+            east_longitude,
+            SunElevationThreshold::AstronomicalTwilight
+        );
+
+        let horizon = horizon.unwrap();
+        let civil = civil.unwrap();
+        let astronomical = astronomical.unwrap();
+
+        assert!(civil.daylight_hours > horizon.daylight_hours);
+        assert!(astronomical.daylight_hours > civil.daylight_hours);
+    }
+
+    #[test]
+    fn test_polar_day_and_night() {
+        // Northern summer solstice
+        let msd = mars_year_ls_to_msd(37, 90.0).unwrap();
+        let sol = local_sol(msd, 0.0).unwrap();
+
+        let north = sun_times(sol, 85.0, 0.0, SunElevationThreshold::Horizon).unwrap();
+        assert_eq!(north.day_type, DayType::PolarDay);
+        assert_eq!(north.daylight_hours, 24.0);
+        assert!(north.sunrise.is_none());
+
+        let south = sun_times(sol, -85.0, 0.0, SunElevationThreshold::Horizon).unwrap();
+        assert_eq!(south.day_type, DayType::PolarNight);
+        assert_eq!(south.daylight_hours, 0.0);
+        assert!(south.sunset.is_none());
+    }
+
+    #[test]
+    fn test_utc_to_sun_times() {
+        let result = utc_to_sun_times(
+            "2024-11-07T17:58:40.000",
+            18.4447,
+            77.4509,
+            SunElevationThreshold::Horizon
+        ).unwrap();
+
+        let msd = utc_to_msd("2024-11-07T17:58:40.000").unwrap();
+        assert_eq!(result.sol, local_sol(msd, 77.4509).unwrap());
+        assert_eq!(result.day_type, DayType::Normal);
+    }
+
+    #[test]
+    fn test_sun_times_same_meridian() {
+        let east = sun_times(53626, 18.4447, 270.0, SunElevationThreshold::Horizon).unwrap();
+        let west = sun_times(53626, 18.4447, -90.0, SunElevationThreshold::Horizon).unwrap();
+
+        assert_eq!(east, west);
+    }
+
+    #[test]
+    fn test_sun_times_invalid_arguments() {
+        let result = sun_times(53626, 91.0, 0.0, SunElevationThreshold::Horizon);
+        assert_eq!(result.unwrap_err(), SunError::LatitudeOutOfRange);
+
+        let result = sun_times(53626, 0.0, 0.0, SunElevationThreshold::Custom(-91.0));
+        assert_eq!(result.unwrap_err(), SunError::ElevationOutOfRange);
+
+        let result = sun_times(53626, 0.0, 400.0, SunElevationThreshold::Horizon);
+        assert_eq!(result.unwrap_err(), SunError::LongitudeOutOfRange);
+
+        let date_time = "2021-13-29T00:00:00.000";
+        let result = utc_to_sun_times(date_time, 0.0, 0.0, SunElevationThreshold::Horizon);
+        assert_eq!(
+            result.unwrap_err(),
+            SunError::TimeError(TimeError::InvalidDateTimeField(DateTimeField::Month))
+        );
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+/// Calculate the point on the Mars surface where the Sun is directly overhead for a given
+/// Mars Sol Date (MSD).
+///
+/// # Examples
+///
+/// ```
+/// use martian::sun::subsolar_point;
+///
+/// let point = subsolar_point(53626.0011).unwrap();
+/// println!("Subsolar point: {:.3}°N {:.3}°E", point.latitude, point.east_longitude);
+/// ```
+///
+/// # Errors
+///
+/// - `SunError::TimeError(TimeError::<InvalidArgumentError>)`: If the MSD is not finite.
+pub fn subsolar_point(msd: f64) -> Result<SubsolarPoint, SunError> {
+    let declination = solar_declination(msd)?;
+
+    Ok(SubsolarPoint {
+        latitude: planetographic_to_planetocentric(declination),
+        planetographic_latitude: declination,
+        east_longitude: subsolar_east_longitude(msd)?,
+    })
+}
+
+/// Calculate the subsolar point for a given UTC datetime. Requires an ISO8601 formatted datetime
+/// string as input.
+///
+/// # Examples
+///
+/// ```
+/// use martian::sun::utc_to_subsolar_point;
+///
+/// let point = utc_to_subsolar_point("2024-11-07T17:58:40.000").unwrap();
+/// println!("Subsolar point: {:.3}°N {:.3}°E", point.latitude, point.east_longitude);
+/// ```
+///
+/// # Errors
+///
+/// May propagate errors from `utc_to_msd`:
+///
+/// - `SunError::TimeError(TimeError::<ISO8601FormatError>)`
+/// - `SunError::TimeError(TimeError::<InvalidDateTimeField>)`
+/// - `SunError::TimeError(TimeError::<TimeCalculationError>)`
+pub fn utc_to_subsolar_point(datetime: &str) -> Result<SubsolarPoint, SunError> {
+    let msd = utc_to_msd(datetime)?;
+    subsolar_point(msd)
+}
+
+/// Calculate the position of the Sun in the sky of a Mars surface site for a given Mars Sol
+/// Date (MSD).
+///
+/// # Arguments
+///
+/// * `msd` - Martian Sol Date for which the position should be calculated.
+/// * `latitude` - Planetographic latitude of the site in degrees (-90 to 90).
+/// * `east_longitude` - Planetocentric east longitude of the site in degrees (-360 to 360).
+///
+/// # Examples
+///
+/// ```
+/// use martian::sun::solar_position;
+///
+/// // Curiosity Rover at Gale crater
+/// let position = solar_position(53626.0011, -4.5895, 137.4417).unwrap();
+/// println!("Elevation: {:.2}°, Azimuth: {:.2}°", position.elevation, position.azimuth);
+/// ```
+///
+/// # Errors
+///
+/// - `SunError::LatitudeOutOfRange`: If the latitude is below -90 or above 90 degrees.
+/// - `SunError::LongitudeOutOfRange`: If the longitude is below -360 or above 360 degrees.
+/// - `SunError::TimeError(TimeError::<InvalidArgumentError>)`: If the MSD is not finite.
+pub fn solar_position(
+    msd: f64,
+    latitude: f64,
+    east_longitude: f64
+) -> Result<SolarPosition, SunError> {
+    validate_latitude(latitude)?;
+    validate_longitude(east_longitude)?;
+
+    let declination = solar_declination(msd)?.to_radians();
+    let latitude = latitude.to_radians();
+
+    // Hour angle is positive before local noon, while the Sun is east of the site
+    let hour_angle = (subsolar_east_longitude(msd)? - east_longitude).to_radians();
+
+    let cos_zenith =
+        declination.sin() * latitude.sin() +
+        declination.cos() * latitude.cos() * hour_angle.cos();
+    let zenith = cos_zenith.clamp(-1.0, 1.0).acos().to_degrees();
+
+    let azimuth = hour_angle
+        .sin()
+        .atan2(latitude.cos() * declination.tan() - latitude.sin() * hour_angle.cos())
+        .to_degrees()
+        .rem_euclid(360.0);
+
+    Ok(SolarPosition {
+        zenith,
+        elevation: 90.0 - zenith,
+        azimuth,
+    })
+}
+
+/// Calculate the position of the Sun in the sky of a Mars surface site for a given UTC datetime.
+/// Requires an ISO8601 formatted datetime string as input.
+///
+/// # Examples
+///
+/// ```
+/// use martian::sun::utc_to_solar_position;
+///
+/// let position = utc_to_solar_position("2024-11-07T17:58:40.000", 18.4447, 77.4509).unwrap();
+/// println!("Zenith angle: {:.2}°", position.zenith);
+/// ```
+///
+/// # Errors
+///
+/// - `SunError::LatitudeOutOfRange`: If the latitude is below -90 or above 90 degrees.
+/// - `SunError::LongitudeOutOfRange`: If the longitude is below -360 or above 360 degrees.
+///
+/// May propagate errors from `utc_to_msd`:
+///
+/// - `SunError::TimeError(TimeError::<ISO8601FormatError>)`
+/// - `SunError::TimeError(TimeError::<InvalidDateTimeField>)`
+/// - `SunError::TimeError(TimeError::<TimeCalculationError>)`
+pub fn utc_to_solar_position(
+    datetime: &str,
+    latitude: f64,
+    east_longitude: f64
+) -> Result<SolarPosition, SunError> {
+    let msd = utc_to_msd(datetime)?;
+    solar_position(msd, latitude, east_longitude)
+}
+
+// Planetocentric east longitude of the subsolar point, step D-1 of the Mars24 algorithm
+// converted from west to east longitude
+fn subsolar_east_longitude(msd: f64) -> Result<f64, SunError> {
+    let mtc_hours = (24.0 * msd).rem_euclid(24.0);
+    let west_longitude = mtc_hours * 15.0 + equation_of_time(msd)? + 180.0;
+
+    Ok((-west_longitude).rem_euclid(360.0))
+}
+
+// Converts planetographic latitude to planetocentric latitude on the Mars reference ellipsoid
+fn planetographic_to_planetocentric(latitude: f64) -> f64 {
+    let ratio = (MARS_POLAR_RADIUS_KM / MARS_EQUATORIAL_RADIUS_KM).powi(2);
+    (ratio * latitude.to_radians().tan()).atan().to_degrees()
+}
+
+#[cfg(test)]
+mod solar_position_tests {
+    use super::*;
+    use crate::time::{ mars_year_ls_to_msd, TimeError };
+
+    // Worked example from Allison & McEwen (2000) for 2000-01-06T00:00:00 UTC
+    fn example_msd() -> f64 {
+        utc_to_msd("2000-01-06T00:00:00.000").unwrap()
+    }
+
+    #[test]
+    fn test_subsolar_point() {
+        let result = subsolar_point(example_msd()).unwrap();
+
+        // Λs = 174.72600°W, δs = -25.22774°
+        assert!(
+            (result.east_longitude - 185.274).abs() < 0.001,
+            "Longitude: {}",
+            result.east_longitude
+        );
+        assert!(
+            (result.planetographic_latitude + 25.2277).abs() < 0.001,
+            "Latitude: {}",
+            result.planetographic_latitude
+        );
+
+        // Planetocentric latitude is closer to the equator
+        assert!(result.latitude > result.planetographic_latitude);
+        assert!((result.latitude + 24.96).abs() < 0.01, "Latitude: {}", result.latitude);
+    }
+
+    #[test]
+    fn test_sun_overhead_at_subsolar_point() {
+        let msd = example_msd();
+        let point = subsolar_point(msd).unwrap();
+
+        let position = solar_position(msd, point.planetographic_latitude, point.east_longitude);
+        let position = position.unwrap();
+        assert!(position.zenith < 1.0e-6, "Zenith: {}", position.zenith);
+
+        // Local true solar noon at the subsolar longitude
+        let ltst = ltst(msd, point.east_longitude).unwrap();
+        assert_eq!((ltst.hours, ltst.minutes), (12, 0));
+    }
+
+    #[test]
+    fn test_solar_position_morning_and_afternoon() {
+        let msd = mars_year_ls_to_msd(37, 0.0).unwrap();
+        let point = subsolar_point(msd).unwrap();
+
+        // Site west of the subsolar point sees the Sun in the east
+        let morning = solar_position(msd, 0.0, point.east_longitude - 45.0).unwrap();
+        assert!((morning.azimuth - 90.0).abs() < 1.0, "Azimuth: {}", morning.azimuth);
+        assert!((morning.elevation - 45.0).abs() < 0.5, "Elevation: {}", morning.elevation);
+
+        // Site east of the subsolar point sees the Sun in the west
+        let afternoon = solar_position(msd, 0.0, point.east_longitude + 45.0).unwrap();
+        assert!((afternoon.azimuth - 270.0).abs() < 1.0, "Azimuth: {}", afternoon.azimuth);
+
+        // Night on the opposite side of the planet
+        let night = solar_position(msd, 0.0, point.east_longitude - 180.0).unwrap();
+        assert!(night.elevation < -89.0, "Elevation: {}", night.elevation);
+    }
+
+    #[test]
+    fn test_solar_position_at_noon_points_to_equator() {
+        // Southern summer, Sun is south of a northern site at noon
+        let msd = mars_year_ls_to_msd(37, 270.0).unwrap();
+        let point = subsolar_point(msd).unwrap();
+
+        let position = solar_position(msd, 40.0, point.east_longitude).unwrap();
+        assert!((position.azimuth - 180.0).abs() < 1.0e-6, "Azimuth: {}", position.azimuth);
+        assert!(
+            (position.elevation - (90.0 - 40.0 + point.planetographic_latitude)).abs() < 1.0e-6,
+            "Elevation: {}",
+            position.elevation
+        );
+    }
+
+    #[test]
+    fn test_utc_to_solar_position() {
+        let date_time = "2024-11-07T17:58:40.000";
+        let msd = utc_to_msd(date_time).unwrap();
+
+        let result = utc_to_solar_position(date_time, 18.4447, 77.4509).unwrap();
+        assert_eq!(result, solar_position(msd, 18.4447, 77.4509).unwrap());
+
+        let result = utc_to_subsolar_point(date_time).unwrap();
+        assert_eq!(result, subsolar_point(msd).unwrap());
+    }
+
+    #[test]
+    fn test_solar_position_invalid_arguments() {
+        assert_eq!(solar_position(53626.0, 90.5, 0.0).unwrap_err(), SunError::LatitudeOutOfRange);
+        assert_eq!(
+            solar_position(53626.0, 0.0, -361.0).unwrap_err(),
+            SunError::LongitudeOutOfRange
+        );
+        assert_eq!(
+            subsolar_point(f64::NAN).unwrap_err(),
+            SunError::TimeError(TimeError::InvalidArgumentError)
+        );
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+/// Apparent angular diameter of the Sun seen from Mars in degrees for a given MSD.
+///
+/// # Examples
+///
+/// ```
+/// use martian::sun::apparent_solar_diameter;
+///
+/// let diameter = apparent_solar_diameter(53626.0011).unwrap();
+/// println!("Apparent diameter of the Sun: {:.4}°", diameter);
+/// ```
+///
+/// # Errors
+///
+/// - `SunError::TimeError(TimeError::<InvalidArgumentError>)`: If the MSD is not finite.
+pub fn apparent_solar_diameter(msd: f64) -> Result<f64, SunError> {
+    let distance_km = heliocentric_distance(msd)? * AU_KM;
+    Ok(2.0 * (SUN_RADIUS_KM / distance_km).atan().to_degrees())
+}
+
+/// Solar irradiance at the top of the Mars atmosphere in W/m², measured on a surface facing
+/// the Sun, for a given MSD.
+///
+/// # Examples
+///
+/// ```
+/// use martian::sun::solar_irradiance;
+///
+/// let irradiance = solar_irradiance(53626.0011).unwrap();
+/// println!("Solar irradiance: {:.1} W/m²", irradiance);
+/// ```
+///
+/// # Errors
+///
+/// - `SunError::TimeError(TimeError::<InvalidArgumentError>)`: If the MSD is not finite.
+pub fn solar_irradiance(msd: f64) -> Result<f64, SunError> {
+    let distance = heliocentric_distance(msd)?;
+    Ok(SOLAR_CONSTANT / distance.powi(2))
+}
+
+/// Direct solar irradiance on a horizontal surface at a Mars site in W/m² for a given MSD.
+/// Without dust optical depth the value is given for the top of the atmosphere, otherwise the
+/// direct beam is attenuated following the Beer-Lambert law. Diffuse light is not included.
+///
+/// # Arguments
+///
+/// * `msd` - Martian Sol Date for which the irradiance should be calculated.
+/// * `latitude` - Planetographic latitude of the site in degrees (-90 to 90).
+/// * `east_longitude` - Planetocentric east longitude of the site in degrees (-360 to 360).
+/// * `dust_optical_depth` - Optional normal dust optical depth (tau) of the atmosphere.
+///
+/// # Examples
+///
+/// ```
+/// use martian::sun::horizontal_irradiance;
+///
+/// let irradiance = horizontal_irradiance(53626.0011, -4.5895, 137.4417, Some(0.5)).unwrap();
+/// println!("Direct irradiance at Gale crater: {:.1} W/m²", irradiance);
+/// ```
+///
+/// # Errors
+///
+/// - `SunError::LatitudeOutOfRange`: If the latitude is below -90 or above 90 degrees.
+/// - `SunError::LongitudeOutOfRange`: If the longitude is below -360 or above 360 degrees.
+/// - `SunError::OpticalDepthOutOfRange`: If the dust optical depth is negative or not finite.
+/// - `SunError::TimeError(TimeError::<InvalidArgumentError>)`: If the MSD is not finite.
+pub fn horizontal_irradiance(
+    msd: f64,
+    latitude: f64,
+    east_longitude: f64,
+    dust_optical_depth: Option<f64>
+) -> Result<f64, SunError> {
+    let tau = validate_optical_depth(dust_optical_depth)?;
+    let position = solar_position(msd, latitude, east_longitude)?;
+    let irradiance = solar_irradiance(msd)?;
+
+    let cos_zenith = position.zenith.to_radians().cos();
+    Ok(irradiance * attenuated_cos_zenith(cos_zenith, tau))
+}
+
+/// Solar energy received during one sol by a horizontal surface at a given latitude in J/m².
+/// Solar declination and distance are taken at the given MSD and assumed constant over the sol.
+/// Without dust optical depth the value is given for the top of the atmosphere, otherwise the
+/// direct beam is attenuated following the Beer-Lambert law. Diffuse light is not included.
+///
+/// # Arguments
+///
+/// * `msd` - Martian Sol Date of the sol.
+/// * `latitude` - Planetographic latitude of the site in degrees (-90 to 90).
+/// * `dust_optical_depth` - Optional normal dust optical depth (tau) of the atmosphere.
+///
+/// # Examples
+///
+/// ```
+/// use martian::sun::daily_insolation;
+///
+/// let insolation = daily_insolation(53626.0011, 18.4447, None).unwrap();
+/// println!("Daily insolation at Jezero crater: {:.2} MJ/m²", insolation / 1.0e6);
+/// ```
+///
+/// # Errors
+///
+/// - `SunError::LatitudeOutOfRange`: If the latitude is below -90 or above 90 degrees.
+/// - `SunError::OpticalDepthOutOfRange`: If the dust optical depth is negative or not finite.
+/// - `SunError::TimeError(TimeError::<InvalidArgumentError>)`: If the MSD is not finite.
+pub fn daily_insolation(
+    msd: f64,
+    latitude: f64,
+    dust_optical_depth: Option<f64>
+) -> Result<f64, SunError> {
+    validate_latitude(latitude)?;
+    let tau = validate_optical_depth(dust_optical_depth)?;
+
+    let irradiance = solar_irradiance(msd)?;
+    let declination = solar_declination(msd)?.to_radians();
+    let latitude = latitude.to_radians();
+    let sol_seconds = SOL_IN_EARTH_DAYS * 86400.0;
+
+    // Integrate cosine of the zenith angle over the hour angle of a full sol
+    const STEPS: usize = 720;
+    let step = std::f64::consts::TAU / (STEPS as f64);
+    let sum: f64 = (0..STEPS)
+        .map(|i| {
+            let hour_angle = -std::f64::consts::PI + ((i as f64) + 0.5) * step;
+            let cos_zenith =
+                declination.sin() * latitude.sin() +
+                declination.cos() * latitude.cos() * hour_angle.cos();
+            attenuated_cos_zenith(cos_zenith, tau)
+        })
+        .sum();
+
+    Ok(irradiance * sol_seconds * sum / (STEPS as f64))
+}
+
+// Cosine of the zenith angle reduced by the atmospheric extinction of the direct beam,
+// zero when the Sun is below the horizon
+fn attenuated_cos_zenith(cos_zenith: f64, tau: f64) -> f64 {
+    if cos_zenith <= 0.0 {
+        0.0
+    } else {
+        cos_zenith * (-tau / cos_zenith).exp()
+    }
+}
+
+fn validate_optical_depth(dust_optical_depth: Option<f64>) -> Result<f64, SunError> {
+    match dust_optical_depth {
+        None => Ok(0.0),
+        Some(tau) if tau.is_finite() && tau >= 0.0 => Ok(tau),
+        Some(_) => Err(SunError::OpticalDepthOutOfRange),
+    }
+}
+
+#[cfg(test)]
+mod irradiance_tests {
+    use super::*;
+    use crate::time::{ mars_year_ls_to_msd, TimeError };
+
+    // Sols of Mars Year 37 around perihelion (Ls ~251°) and aphelion (Ls ~71°)
+    fn perihelion_and_aphelion_msd() -> (f64, f64) {
+        let distance = |msd: &f64| heliocentric_distance(*msd).unwrap();
+        let sols: Vec<f64> = (0..669).map(|sol| 52961.0 + (sol as f64)).collect();
+
+        let perihelion = sols.iter().min_by(|a, b| distance(a).total_cmp(&distance(b)));
+        let aphelion = sols.iter().max_by(|a, b| distance(a).total_cmp(&distance(b)));
+
+        (*perihelion.unwrap(), *aphelion.unwrap())
+    }
+
+    #[test]
+    fn test_apparent_solar_diameter() {
+        let (perihelion, aphelion) = perihelion_and_aphelion_msd();
+
+        let max = apparent_solar_diameter(perihelion).unwrap();
+        let min = apparent_solar_diameter(aphelion).unwrap();
+
+        assert!((max - 0.3859).abs() < 0.001, "Diameter: {}", max);
+        assert!((min - 0.3200).abs() < 0.001, "Diameter: {}", min);
+    }
+
+    #[test]
+    fn test_solar_irradiance() {
+        let (perihelion, aphelion) = perihelion_and_aphelion_msd();
+
+        let max = solar_irradiance(perihelion).unwrap();
+        let min = solar_irradiance(aphelion).unwrap();
+
+        assert!((max - 713.2).abs() < 1.0, "Irradiance: {}", max);
+        assert!((min - 490.4).abs() < 1.0, "Irradiance: {}", min);
+    }
+
+    #[test]
+    fn test_horizontal_irradiance() {
+        let msd = mars_year_ls_to_msd(37, 0.0).unwrap();
+        let point = subsolar_point(msd).unwrap();
+        let latitude = point.planetographic_latitude;
+        let irradiance = solar_irradiance(msd).unwrap();
+
+        // Sun at zenith
+        let result = horizontal_irradiance(msd, latitude, point.east_longitude, None).unwrap();
+        assert!((result - irradiance).abs() < 1.0e-6, "Irradiance: {}", result);
+
+        let result = horizontal_irradiance(msd, latitude, point.east_longitude, Some(1.0));
+        let result = result.unwrap();
+        assert!((result - irradiance * (-1.0_f64).exp()).abs() < 1.0e-6, "Irradiance: {}", result);
+
+        // Sun below the horizon
+        let result = horizontal_irradiance(msd, latitude, point.east_longitude - 180.0, None);
+        assert_eq!(result.unwrap(), 0.0);
+    }
+
+    #[test]
+    fn test_daily_insolation() {
+        let msd = mars_year_ls_to_msd(37, 0.0).unwrap();
+        let irradiance = solar_irradiance(msd).unwrap();
+        let declination = solar_declination(msd).unwrap().to_radians();
+        let sol_seconds = SOL_IN_EARTH_DAYS * 86400.0;
+
+        // Analytical daily insolation at the top of the atmosphere
+        let latitude = 30.0_f64.to_radians();
+        let half_arc = (-latitude.tan() * declination.tan()).acos();
+        let expected =
+            ((irradiance * sol_seconds) / std::f64::consts::PI) *
+            (half_arc * latitude.sin() * declination.sin() +
+                latitude.cos() * declination.cos() * half_arc.sin());
+
+        let result = daily_insolation(msd, 30.0, None).unwrap();
+        assert!((result - expected).abs() / expected < 1.0e-4, "{} != {}", result, expected);
+
+        // Dust reduces the energy reaching the surface
+        let dusty = daily_insolation(msd, 30.0, Some(2.0)).unwrap();
+        assert!(dusty < result * 0.25, "Dusty insolation: {}", dusty);
+
+        // Polar night
+        let msd = mars_year_ls_to_msd(37, 90.0).unwrap();
+        assert_eq!(daily_insolation(msd, -85.0, None).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn test_irradiance_invalid_arguments() {
+        let result = daily_insolation(53626.0, 0.0, Some(-0.1));
+        assert_eq!(result.unwrap_err(), SunError::OpticalDepthOutOfRange);
+
+        let result = horizontal_irradiance(53626.0, 0.0, 0.0, Some(f64::NAN));
+        assert_eq!(result.unwrap_err(), SunError::OpticalDepthOutOfRange);
+
+        let result = daily_insolation(53626.0, -91.0, None);
+        assert_eq!(result.unwrap_err(), SunError::LatitudeOutOfRange);
+
+        let result = solar_irradiance(f64::INFINITY);
+        assert_eq!(result.unwrap_err(), SunError::TimeError(TimeError::InvalidArgumentError));
+    }
+}
@@ -0,0 +1,11 @@
+//! Module responsible for handling Sun related operations as seen from the surface of Mars.
+
+mod constants;
+mod functions;
+mod structs;
+mod errors;
+
+pub use functions::*;
+pub use constants::*;
+pub use structs::*;
+pub use errors::*;
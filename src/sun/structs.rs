@@ -0,0 +1,104 @@
+use std::fmt;
+use crate::sun::constants::{
+    ASTRONOMICAL_TWILIGHT_ELEVATION,
+    CIVIL_TWILIGHT_ELEVATION,
+    NAUTICAL_TWILIGHT_ELEVATION,
+    SUN_APPARENT_RADIUS,
+};
+use crate::time::Time;
+
+// ------------------------------------------------------------------------------------------------
+/// Solar elevation at which the Sun is considered to rise or set.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SunElevationThreshold {
+    /// Upper limb of the Sun touches the horizon. Mars atmosphere refraction is negligible.
+    Horizon,
+    /// Centre of the Sun 6° below the horizon.
+    CivilTwilight,
+    /// Centre of the Sun 12° below the horizon.
+    NauticalTwilight,
+    /// Centre of the Sun 18° below the horizon.
+    AstronomicalTwilight,
+    /// Custom elevation of the centre of the Sun in degrees.
+    Custom(f64),
+}
+
+impl SunElevationThreshold {
+    /// Returns the elevation of the centre of the Sun in degrees.
+    pub fn degrees(&self) -> f64 {
+        match self {
+            SunElevationThreshold::Horizon => -SUN_APPARENT_RADIUS,
+            SunElevationThreshold::CivilTwilight => CIVIL_TWILIGHT_ELEVATION,
+            SunElevationThreshold::NauticalTwilight => NAUTICAL_TWILIGHT_ELEVATION,
+            SunElevationThreshold::AstronomicalTwilight => ASTRONOMICAL_TWILIGHT_ELEVATION,
+            SunElevationThreshold::Custom(degrees) => *degrees,
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+/// Describes whether the Sun crosses the elevation threshold during a sol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DayType {
+    /// The Sun rises and sets during the sol.
+    Normal,
+    /// The Sun stays above the threshold for the whole sol.
+    PolarDay,
+    /// The Sun stays below the threshold for the whole sol.
+    PolarNight,
+}
+
+// ------------------------------------------------------------------------------------------------
+/// Represents a moment the Sun crosses the elevation threshold at a given site.
+#[derive(Debug, PartialEq)]
+pub struct SolarEvent {
+    pub msd: f64,
+    pub lmst: Time,
+    pub ltst: Time,
+    pub utc: String,
+}
+
+impl fmt::Display for SolarEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} LMST ({} UTC)", self.lmst, self.utc)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+/// Represents sunrise and sunset of a single local sol at a given site.
+#[derive(Debug, PartialEq)]
+pub struct SunTimes {
+    /// Local sol number the times were calculated for.
+    pub sol: i64,
+    pub day_type: DayType,
+    /// Present only for `DayType::Normal`.
+    pub sunrise: Option<SolarEvent>,
+    /// Present only for `DayType::Normal`.
+    pub sunset: Option<SolarEvent>,
+    /// Length of the day in Mars hours (1/24 of a sol).
+    pub daylight_hours: f64,
+}
+
+// ------------------------------------------------------------------------------------------------
+/// Position of the Sun in the sky of a Mars surface site. All values are in degrees.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SolarPosition {
+    /// Angle between the local vertical and the direction to the Sun (0 to 180).
+    pub zenith: f64,
+    /// Angle of the Sun above the horizon (-90 to 90), equal to `90 - zenith`.
+    pub elevation: f64,
+    /// Direction to the Sun measured clockwise from north, 90 being east (0 to 360).
+    pub azimuth: f64,
+}
+
+// ------------------------------------------------------------------------------------------------
+/// Point on the Mars surface where the Sun is directly overhead. All values are in degrees.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SubsolarPoint {
+    /// Planetocentric latitude (-90 to 90).
+    pub latitude: f64,
+    /// Planetographic latitude (-90 to 90), equal to the solar declination.
+    pub planetographic_latitude: f64,
+    /// Planetocentric east longitude (0 to 360).
+    pub east_longitude: f64,
+}
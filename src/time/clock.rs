@@ -0,0 +1,506 @@
+use std::sync::{ Arc, Mutex, MutexGuard, PoisonError };
+use hifitime::{ Duration, Epoch };
+use crate::time::errors::TimeError;
+use crate::time::functions::parse_utc_epoch;
+use crate::time::structs::{ MarsDuration, MarsInstant };
+
+// ------------------------------------------------------------------------------------------------
+/// Source of the current instant used by the `*_now_with_clock` functions. Implement it to pin
+/// Mars time in test suites or to replay recorded telemetry.
+///
+/// # Examples
+///
+/// ```
+/// use martian::time::{ msd_now_with_clock, FixedClock };
+///
+/// let clock = FixedClock::from_utc("2012-08-06T05:17:57Z").unwrap();
+/// let msd = msd_now_with_clock(&clock).unwrap();
+///
+/// assert!((msd - 49269.25).abs() < 0.01);
+/// ```
+pub trait Clock {
+    /// Returns the current instant as a hifitime `Epoch` in any time scale.
+    ///
+    /// # Errors
+    ///
+    /// - `TimeError::UtcTimeUnavailable`: If the current time cannot be retrieved.
+    fn now(&self) -> Result<Epoch, TimeError>;
+}
+
+// ------------------------------------------------------------------------------------------------
+/// Clock reading the system time. Used by `msd_now`, `mtc_now` and the other `*_now` functions.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Result<Epoch, TimeError> {
+        Epoch::now().map_err(|_| TimeError::UtcTimeUnavailable)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+/// Clock always returning the same instant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedClock {
+    epoch: Epoch,
+}
+
+impl FixedClock {
+    /// Constructs a new `FixedClock` instance pinned to the provided `Epoch`.
+    pub fn new(epoch: Epoch) -> Self {
+        Self { epoch }
+    }
+
+    /// Constructs a new `FixedClock` instance pinned to an ISO8601 formatted UTC datetime string.
+    ///
+    /// # Errors
+    ///
+    /// - `TimeError::ISO8601FormatError`: If the provided date does not match the ISO8601 format.
+    /// - `TimeError::InvalidDateTimeField`: If a datetime field is out of range.
+    pub fn from_utc(datetime: &str) -> Result<Self, TimeError> {
+        Ok(Self::new(parse_utc_epoch(datetime)?))
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> Result<Epoch, TimeError> {
+        Ok(self.epoch)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+/// Clock shifting another clock by a constant offset, eg. to replay a past pass in real time.
+///
+/// # Examples
+///
+/// ```
+/// use hifitime::Unit;
+/// use martian::time::{ Clock, OffsetClock, SystemClock };
+///
+/// // One Earth day in the past
+/// let clock = OffsetClock::new(SystemClock, -(Unit::Day * 1));
+/// assert!(clock.now().unwrap() < SystemClock.now().unwrap());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OffsetClock<C = SystemClock>
+    where C: Clock
+{
+    clock: C,
+    offset: Duration,
+}
+
+impl<C> OffsetClock<C>
+    where C: Clock
+{
+    /// Constructs a new `OffsetClock` instance adding `offset` to every reading of `clock`.
+    pub fn new(clock: C, offset: Duration) -> Self {
+        Self { clock, offset }
+    }
+
+    /// Returns the offset added to the wrapped clock.
+    pub fn offset(&self) -> Duration {
+        self.offset
+    }
+}
+
+impl<C> Clock for OffsetClock<C>
+    where C: Clock
+{
+    fn now(&self) -> Result<Epoch, TimeError> {
+        Ok(self.clock.now()? + self.offset)
+    }
+}
+
+impl<C> Clock for &C
+    where C: Clock + ?Sized
+{
+    fn now(&self) -> Result<Epoch, TimeError> {
+        (**self).now()
+    }
+}
+
+impl<C> Clock for Arc<C>
+    where C: Clock + ?Sized
+{
+    fn now(&self) -> Result<Epoch, TimeError> {
+        (**self).now()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+/// Virtual clock for games and mission rehearsals. Runs Mars time from any starting instant at a
+/// chosen rate and can be paused, stepped sol by sol and jumped.
+///
+/// Every reading goes through one shared state, so threads holding the same clock (eg. behind an
+/// `Arc`) agree on the current instant and on the MTC, LMST and Darian dates derived from it.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::Arc;
+/// use martian::time::{ lmst_now_with_clock, mtc_now_with_clock, SimulationClock };
+///
+/// // Curiosity Rover landing, MTC 06:00:00
+/// let clock = Arc::new(SimulationClock::from_msd(49269.25).unwrap());
+/// clock.pause().unwrap();
+/// clock.step_sols(1).unwrap();
+///
+/// let mtc = mtc_now_with_clock(&clock).unwrap();
+/// assert_eq!((mtc.hours, mtc.minutes), (6, 0));
+///
+/// let lmst = lmst_now_with_clock(&clock, 137.4417).unwrap();
+/// assert_eq!((lmst.hours, lmst.minutes), (15, 9));
+/// ```
+#[derive(Debug)]
+pub struct SimulationClock<C = SystemClock>
+    where C: Clock
+{
+    source: C,
+    state: Mutex<SimulationState>,
+}
+
+// Simulated instant `anchor` was reached when the source read `source_anchor`
+#[derive(Debug, Clone, Copy)]
+struct SimulationState {
+    source_anchor: Epoch,
+    anchor: Epoch,
+    scale: f64,
+    paused: bool,
+}
+
+impl SimulationState {
+    fn at(&self, source_now: Epoch) -> Epoch {
+        if self.paused {
+            self.anchor
+        } else {
+            self.anchor + (source_now - self.source_anchor) * self.scale
+        }
+    }
+}
+
+impl SimulationClock {
+    /// Constructs a new `SimulationClock` instance starting at `start` and running in real time.
+    ///
+    /// # Errors
+    ///
+    /// - `TimeError::UtcTimeUnavailable`: If the current UTC time cannot be retrieved.
+    pub fn new(start: MarsInstant) -> Result<Self, TimeError> {
+        Self::with_source(SystemClock, start)
+    }
+
+    /// Constructs a new `SimulationClock` instance starting at the provided MSD.
+    ///
+    /// # Errors
+    ///
+    /// - `TimeError::InvalidArgumentError`: If the MSD is not finite.
+    /// - `TimeError::TimeCalculationError`: If the MSD is outside the range of a hifitime `Epoch`.
+    /// - `TimeError::UtcTimeUnavailable`: If the current UTC time cannot be retrieved.
+    pub fn from_msd(msd: f64) -> Result<Self, TimeError> {
+        Self::new(MarsInstant::from_msd(msd)?)
+    }
+}
+
+impl<C> SimulationClock<C>
+    where C: Clock
+{
+    /// Constructs a new `SimulationClock` instance starting at `start`, advanced by the readings of
+    /// `source` instead of the system clock.
+    ///
+    /// # Errors
+    ///
+    /// - `TimeError::UtcTimeUnavailable`: If the source clock can't provide the current time.
+    pub fn with_source(source: C, start: MarsInstant) -> Result<Self, TimeError> {
+        let state = SimulationState {
+            source_anchor: source.now()?,
+            anchor: start.epoch(),
+            scale: 1.0,
+            paused: false,
+        };
+
+        Ok(Self { source, state: Mutex::new(state) })
+    }
+
+    /// Returns the current simulated instant.
+    ///
+    /// # Errors
+    ///
+    /// - `TimeError::UtcTimeUnavailable`: If the source clock can't provide the current time.
+    pub fn instant(&self) -> Result<MarsInstant, TimeError> {
+        Ok(MarsInstant::from_epoch(self.now()?))
+    }
+
+    /// Returns how many simulated seconds pass per second of the source clock.
+    pub fn scale(&self) -> f64 {
+        self.lock().scale
+    }
+
+    /// Sets how many simulated seconds pass per second of the source clock, eg. 10.0 runs Mars time
+    /// ten times faster. Time already simulated is kept.
+    ///
+    /// # Errors
+    ///
+    /// - `TimeError::InvalidArgumentError`: If the scale is negative or not finite.
+    /// - `TimeError::UtcTimeUnavailable`: If the source clock can't provide the current time.
+    pub fn set_scale(&self, scale: f64) -> Result<(), TimeError> {
+        if !scale.is_finite() || scale < 0.0 {
+            return Err(TimeError::InvalidArgumentError);
+        }
+
+        self.update(|state| state.scale = scale)
+    }
+
+    /// Returns `true` if the simulation is paused.
+    pub fn is_paused(&self) -> bool {
+        self.lock().paused
+    }
+
+    /// Freezes the simulated instant until `resume` is called.
+    ///
+    /// # Errors
+    ///
+    /// - `TimeError::UtcTimeUnavailable`: If the source clock can't provide the current time.
+    pub fn pause(&self) -> Result<(), TimeError> {
+        self.update(|state| state.paused = true)
+    }
+
+    /// Continues the simulation from the instant it was paused at.
+    ///
+    /// # Errors
+    ///
+    /// - `TimeError::UtcTimeUnavailable`: If the source clock can't provide the current time.
+    pub fn resume(&self) -> Result<(), TimeError> {
+        self.update(|state| state.paused = false)
+    }
+
+    /// Moves the simulated instant by the provided duration, negative durations move it back.
+    ///
+    /// # Errors
+    ///
+    /// - `TimeError::UtcTimeUnavailable`: If the source clock can't provide the current time.
+    pub fn advance(&self, duration: MarsDuration) -> Result<(), TimeError> {
+        self.update(|state| state.anchor += duration.to_duration())
+    }
+
+    /// Moves the simulated instant by whole sols, keeping the time of sol.
+    ///
+    /// # Errors
+    ///
+    /// - `TimeError::UtcTimeUnavailable`: If the source clock can't provide the current time.
+    pub fn step_sols(&self, sols: i64) -> Result<(), TimeError> {
+        self.advance(MarsDuration::from_sols(sols as f64))
+    }
+
+    /// Sets the simulated instant, keeping the scale and the paused state.
+    ///
+    /// # Errors
+    ///
+    /// - `TimeError::UtcTimeUnavailable`: If the source clock can't provide the current time.
+    pub fn jump_to(&self, instant: MarsInstant) -> Result<(), TimeError> {
+        self.update(|state| state.anchor = instant.epoch())
+    }
+
+    /// Sets the simulated instant to the provided MSD, keeping the scale and the paused state.
+    ///
+    /// # Errors
+    ///
+    /// - `TimeError::InvalidArgumentError`: If the MSD is not finite.
+    /// - `TimeError::TimeCalculationError`: If the MSD is outside the range of a hifitime `Epoch`.
+    /// - `TimeError::UtcTimeUnavailable`: If the source clock can't provide the current time.
+    pub fn jump_to_msd(&self, msd: f64) -> Result<(), TimeError> {
+        self.jump_to(MarsInstant::from_msd(msd)?)
+    }
+
+    // State is plain data that is always consistent, so a poisoned lock is safe to reuse
+    fn lock(&self) -> MutexGuard<'_, SimulationState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    // Re-anchors the simulation at the current source reading before applying the change
+    fn update<F>(&self, change: F) -> Result<(), TimeError>
+        where F: FnOnce(&mut SimulationState)
+    {
+        let mut state = self.lock();
+        let source_now = self.source.now()?;
+
+        state.anchor = state.at(source_now);
+        state.source_anchor = source_now;
+        change(&mut state);
+
+        Ok(())
+    }
+}
+
+impl<C> Clock for SimulationClock<C>
+    where C: Clock
+{
+    fn now(&self) -> Result<Epoch, TimeError> {
+        let state = self.lock();
+        Ok(state.at(self.source.now()?))
+    }
+}
+
+#[cfg(test)]
+mod clock_tests {
+    use super::*;
+    use hifitime::Unit;
+
+    #[test]
+    fn test_system_clock() {
+        let before = Epoch::now().unwrap();
+        let now = SystemClock.now().unwrap();
+
+        assert!(now >= before);
+    }
+
+    #[test]
+    fn test_fixed_clock() {
+        let epoch = Epoch::from_gregorian_utc(2012, 8, 6, 5, 17, 57, 0);
+        let clock = FixedClock::new(epoch);
+
+        assert_eq!(clock.now().unwrap(), epoch);
+        assert_eq!(clock.now().unwrap(), clock.now().unwrap());
+        assert_eq!(FixedClock::from_utc("2012-08-06T05:17:57Z").unwrap(), clock);
+        assert_eq!(FixedClock::from_utc("2012-08-06").unwrap_err(), TimeError::ISO8601FormatError);
+    }
+
+    #[test]
+    fn test_offset_clock() {
+        let epoch = Epoch::from_gregorian_utc(2012, 8, 6, 5, 17, 57, 0);
+        let clock = OffsetClock::new(FixedClock::new(epoch), Unit::Hour * 2);
+
+        assert_eq!(clock.offset(), Unit::Hour * 2);
+        assert_eq!(clock.now().unwrap(), epoch + Unit::Hour * 2);
+
+        // Offsets stack when clocks are nested
+        let nested = OffsetClock::new(clock, -(Unit::Hour * 3));
+        assert_eq!(nested.now().unwrap(), epoch - Unit::Hour * 1);
+
+        // Also usable behind a trait object
+        let clock: &dyn Clock = &nested;
+        assert_eq!(clock.now().unwrap(), epoch - Unit::Hour * 1);
+    }
+}
+
+#[cfg(test)]
+mod simulation_clock_tests {
+    use super::*;
+    use std::thread;
+    use hifitime::Unit;
+    use crate::time::functions::{ lmst_now_with_clock, msd_now_with_clock, mtc_now_with_clock };
+    use crate::time::structs::Time;
+
+    // Source clock moved by hand, so the tests don't depend on the wall clock
+    struct ManualClock {
+        epoch: Mutex<Epoch>,
+    }
+
+    impl ManualClock {
+        fn new() -> Self {
+            Self { epoch: Mutex::new(Epoch::from_gregorian_utc(2030, 1, 1, 0, 0, 0, 0)) }
+        }
+
+        fn tick(&self, duration: Duration) {
+            *self.epoch.lock().unwrap() += duration;
+        }
+    }
+
+    impl Clock for ManualClock {
+        fn now(&self) -> Result<Epoch, TimeError> {
+            Ok(*self.epoch.lock().unwrap())
+        }
+    }
+
+    fn start() -> MarsInstant {
+        // Curiosity Rover landing, MTC 06:00:00
+        MarsInstant::from_msd(49269.25).unwrap()
+    }
+
+    #[test]
+    fn test_simulation_clock_runs_at_scale() {
+        let source = ManualClock::new();
+        let clock = SimulationClock::with_source(&source, start()).unwrap();
+
+        assert_eq!(clock.scale(), 1.0);
+        assert_eq!(clock.instant().unwrap(), start());
+
+        source.tick(Unit::Hour * 1);
+        assert_eq!(clock.now().unwrap(), start().epoch() + Unit::Hour * 1);
+
+        clock.set_scale(10.0).unwrap();
+        source.tick(Unit::Hour * 1);
+        assert_eq!(clock.now().unwrap(), start().epoch() + Unit::Hour * 11);
+
+        assert_eq!(clock.set_scale(-1.0).unwrap_err(), TimeError::InvalidArgumentError);
+        assert_eq!(clock.set_scale(f64::INFINITY).unwrap_err(), TimeError::InvalidArgumentError);
+        assert_eq!(clock.scale(), 10.0);
+    }
+
+    #[test]
+    fn test_simulation_clock_pause_and_resume() {
+        let source = ManualClock::new();
+        let clock = SimulationClock::with_source(&source, start()).unwrap();
+
+        clock.pause().unwrap();
+        assert!(clock.is_paused());
+        source.tick(Unit::Day * 3);
+        assert_eq!(clock.instant().unwrap(), start());
+
+        clock.resume().unwrap();
+        assert!(!clock.is_paused());
+        source.tick(Unit::Minute * 5);
+        assert_eq!(clock.now().unwrap(), start().epoch() + Unit::Minute * 5);
+    }
+
+    #[test]
+    fn test_simulation_clock_step_and_jump() {
+        let source = ManualClock::new();
+        let clock = SimulationClock::with_source(&source, start()).unwrap();
+        clock.pause().unwrap();
+
+        clock.step_sols(2).unwrap();
+        assert!((msd_now_with_clock(&clock).unwrap() - 49271.25).abs() < 1.0e-9);
+        assert_eq!(mtc_now_with_clock(&clock).unwrap(), Time::new(6, 0, 0, 0));
+
+        clock.step_sols(-3).unwrap();
+        assert!((msd_now_with_clock(&clock).unwrap() - 49268.25).abs() < 1.0e-9);
+
+        clock.advance(MarsDuration::from_mars_hours(6.0)).unwrap();
+        assert_eq!(mtc_now_with_clock(&clock).unwrap(), Time::new(12, 0, 0, 0));
+
+        clock.jump_to_msd(53626.5).unwrap();
+        assert_eq!(mtc_now_with_clock(&clock).unwrap(), Time::new(12, 0, 0, 0));
+        assert!(clock.is_paused());
+
+        assert_eq!(clock.jump_to_msd(f64::NAN).unwrap_err(), TimeError::InvalidArgumentError);
+    }
+
+    #[test]
+    fn test_simulation_clock_across_threads() {
+        let source = Arc::new(ManualClock::new());
+        let clock = Arc::new(SimulationClock::with_source(source, start()).unwrap());
+        clock.pause().unwrap();
+        let paused = clock.instant().unwrap();
+
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let clock = Arc::clone(&clock);
+                thread::spawn(move || {
+                    let mtc = mtc_now_with_clock(&clock).unwrap();
+                    let lmst = lmst_now_with_clock(&clock, 137.4417).unwrap();
+                    (mtc, lmst)
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            let (mtc, lmst) = handle.join().unwrap();
+            assert_eq!(mtc, paused.mtc());
+            assert_eq!(lmst, paused.lmst(137.4417).unwrap());
+        }
+
+        // Steps taken on one thread are seen by the others
+        let stepper = Arc::clone(&clock);
+        thread::spawn(move || stepper.step_sols(1).unwrap()).join().unwrap();
+        assert_eq!(clock.instant().unwrap(), paused + MarsDuration::from_sols(1.0));
+    }
+}
@@ -3,3 +3,40 @@ pub const SOL_IN_EARTH_DAYS: f64 = 1.0274912517;
 
 /// Julian Date where Mars Sol Date (MSD) is zero.
 pub const JD_ON_SOL_ZERO: f64 = 2405522.0028779;
+
+/// Length of a Martian sol in Earth days used by Allison (1997).
+pub const ALLISON_1997_SOL_IN_EARTH_DAYS: f64 = 1.02749125;
+
+/// Julian Date where Mars Sol Date (MSD) is zero in Allison (1997), from the original
+/// `MSD = (JD_TT - 2451549.5) / 1.02749125 + 44796.0 - 0.00072` relation.
+pub const ALLISON_1997_JD_ON_SOL_ZERO: f64 = 2405522.0027048;
+
+/// Julian Date of the Modified Julian Date (MJD) epoch (1858-11-17T00:00:00).
+pub const JD_ON_MJD_ZERO: f64 = 2400000.5;
+
+/// Number of nanoseconds in an Earth day.
+pub const NANOSECONDS_PER_DAY: f64 = 86_400_000_000_000.0;
+
+/// Julian Date of the J2000.0 epoch (2000-01-01T12:00:00 TT).
+pub const JD_J2000: f64 = 2451545.0;
+
+/// Amplitudes (degrees) of the planetary perturbation terms from Allison & McEwen (2000).
+pub const PERTURBER_AMPLITUDES: [f64; 7] = [0.0071, 0.0057, 0.0039, 0.0037, 0.0021, 0.002, 0.0018];
+
+/// Periods (Julian years) of the planetary perturbation terms from Allison & McEwen (2000).
+pub const PERTURBER_PERIODS: [f64; 7] = [2.2353, 2.7543, 1.1177, 15.7866, 2.1354, 2.4694, 32.8493];
+
+/// Phases (degrees) of the planetary perturbation terms from Allison & McEwen (2000).
+pub const PERTURBER_PHASES: [f64; 7] = [49.409, 168.173, 191.837, 21.736, 15.704, 95.528, 49.095];
+
+/// Length of the Martian tropical year (Ls 0° to Ls 0°) in sols.
+pub const MARS_TROPICAL_YEAR_SOLS: f64 = 668.5921;
+
+/// Approximate MSD of the start of Mars Year 1 (1955-04-11, Ls 0°) as defined by Clancy et al. (2000).
+pub const MARS_YEAR_ONE_START_MSD: f64 = 28892.2;
+
+/// Semi-major axis of the Mars orbit in astronomical units (AU).
+pub const MARS_SEMI_MAJOR_AXIS_AU: f64 = 1.52367934;
+
+/// Width of a Mars24 time zone in degrees of longitude, one Mars hour of MTC offset.
+pub const MARS_TIME_ZONE_WIDTH: f64 = 15.0;
@@ -0,0 +1,333 @@
+use std::fmt;
+use std::time::SystemTime;
+use hifitime::{ Duration, Epoch, TimeScale, Unit };
+use crate::time::errors::TimeError;
+use crate::time::functions::{ epoch_from_system_time, parse_utc_epoch };
+use crate::time::structs::MarsInstant;
+
+// ------------------------------------------------------------------------------------------------
+/// Instant on Earth, returned by `msd_to_earth_time` and accepted by `to_msd`. Converts to
+/// the representations used by Earth pipelines without going through strings.
+///
+/// # Examples
+///
+/// ```
+/// use martian::time::{ msd_to_earth_time, to_msd, EarthTime };
+///
+/// let time = msd_to_earth_time(49269.25).unwrap();
+/// println!("{} = Unix {:.3} = JD {:.6}", time, time.unix_seconds(), time.jd_utc());
+///
+/// // Pipelines holding Unix timestamps feed them in directly
+/// let msd = to_msd(EarthTime::from_unix_seconds(1344230277.0).unwrap()).unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EarthTime {
+    epoch: Epoch,
+}
+
+impl EarthTime {
+    /// Constructs a new `EarthTime` instance from a hifitime `Epoch` in any time scale, including
+    /// TAI and TT epochs.
+    pub fn from_epoch(epoch: Epoch) -> Self {
+        Self { epoch }
+    }
+
+    /// Constructs a new `EarthTime` instance from an ISO8601 / RFC3339 formatted datetime string,
+    /// read as UTC when it has no offset.
+    ///
+    /// # Errors
+    ///
+    /// - `TimeError::ISO8601FormatError`: If the provided date does not match the ISO8601 format.
+    /// - `TimeError::InvalidDateTimeField`: If a datetime field is out of range.
+    pub fn from_utc(datetime: &str) -> Result<Self, TimeError> {
+        Ok(Self::from_epoch(parse_utc_epoch(datetime)?))
+    }
+
+    /// Constructs a new `EarthTime` instance from seconds since the Unix epoch
+    /// (1970-01-01T00:00:00Z), not counting leap seconds.
+    ///
+    /// # Errors
+    ///
+    /// - `TimeError::InvalidArgumentError`: If the timestamp is not finite.
+    pub fn from_unix_seconds(seconds: f64) -> Result<Self, TimeError> {
+        Ok(Self::from_epoch(Epoch::from_unix_seconds(finite(seconds)?)))
+    }
+
+    /// Constructs a new `EarthTime` instance from nanoseconds since the Unix epoch
+    /// (1970-01-01T00:00:00Z), not counting leap seconds.
+    pub fn from_unix_nanoseconds(nanoseconds: i128) -> Self {
+        Self::from_epoch(Epoch::from_unix_duration(Duration::from_total_nanoseconds(nanoseconds)))
+    }
+
+    /// Constructs a new `EarthTime` instance from a Julian Date in UTC.
+    ///
+    /// # Errors
+    ///
+    /// - `TimeError::InvalidArgumentError`: If the Julian Date is not finite.
+    pub fn from_jd_utc(jd: f64) -> Result<Self, TimeError> {
+        Ok(Self::from_epoch(Epoch::from_jde_utc(finite(jd)?)))
+    }
+
+    /// Constructs a new `EarthTime` instance from a Modified Julian Date (MJD) in UTC.
+    ///
+    /// # Errors
+    ///
+    /// - `TimeError::InvalidArgumentError`: If the MJD is not finite.
+    pub fn from_mjd_utc(mjd: f64) -> Result<Self, TimeError> {
+        Ok(Self::from_epoch(Epoch::from_mjd_utc(finite(mjd)?)))
+    }
+
+    /// Constructs a new `EarthTime` instance from a Julian Date in Terrestrial Time (TT).
+    ///
+    /// # Errors
+    ///
+    /// - `TimeError::InvalidArgumentError`: If the Julian Date is not finite.
+    pub fn from_jde_tt(jde_tt: f64) -> Result<Self, TimeError> {
+        Ok(Self::from_epoch(Epoch::from_jde_in_time_scale(finite(jde_tt)?, TimeScale::TT)))
+    }
+
+    /// Constructs a new `EarthTime` instance from seconds since the GPS epoch
+    /// (1980-01-06T00:00:00Z), counting leap seconds.
+    ///
+    /// # Errors
+    ///
+    /// - `TimeError::InvalidArgumentError`: If the GPS time is not finite.
+    pub fn from_gps_seconds(seconds: f64) -> Result<Self, TimeError> {
+        Ok(Self::from_epoch(Epoch::from_gpst_seconds(finite(seconds)?)))
+    }
+
+    /// Returns the underlying hifitime `Epoch`.
+    pub fn epoch(&self) -> Epoch {
+        self.epoch
+    }
+
+    /// Returns the RFC3339 formatted UTC datetime string with a `Z` suffix. Fractional seconds are
+    /// written up to nanoseconds without trailing zeros and leap seconds as `23:59:60`.
+    pub fn to_rfc3339(&self) -> String {
+        self.to_string()
+    }
+
+    /// Returns the seconds since the Unix epoch (1970-01-01T00:00:00Z), not counting leap
+    /// seconds.
+    pub fn unix_seconds(&self) -> f64 {
+        self.epoch.to_unix_seconds()
+    }
+
+    /// Returns the nanoseconds since the Unix epoch (1970-01-01T00:00:00Z), not counting leap
+    /// seconds.
+    pub fn unix_nanoseconds(&self) -> i128 {
+        self.epoch.to_unix_duration().total_nanoseconds()
+    }
+
+    /// Returns the Julian Date in UTC.
+    pub fn jd_utc(&self) -> f64 {
+        self.epoch.to_jde_utc_days()
+    }
+
+    /// Returns the Modified Julian Date (MJD) in UTC.
+    pub fn mjd_utc(&self) -> f64 {
+        self.epoch.to_mjd_utc_days()
+    }
+
+    /// Returns the Julian Date in Terrestrial Time (TT).
+    pub fn jde_tt(&self) -> f64 {
+        self.epoch.to_jde_tt_days()
+    }
+
+    /// Returns the seconds since the GPS epoch (1980-01-06T00:00:00Z), counting leap seconds.
+    pub fn gps_seconds(&self) -> f64 {
+        self.epoch.to_gpst_seconds()
+    }
+
+    /// Returns the instant as a hifitime `Epoch` in International Atomic Time (TAI).
+    pub fn tai(&self) -> Epoch {
+        self.epoch.to_time_scale(TimeScale::TAI)
+    }
+
+    /// Returns the instant as a hifitime `Epoch` in Terrestrial Time (TT).
+    pub fn tt(&self) -> Epoch {
+        self.epoch.to_time_scale(TimeScale::TT)
+    }
+}
+
+fn finite(value: f64) -> Result<f64, TimeError> {
+    if value.is_finite() { Ok(value) } else { Err(TimeError::InvalidArgumentError) }
+}
+
+impl From<Epoch> for EarthTime {
+    fn from(epoch: Epoch) -> Self {
+        Self::from_epoch(epoch)
+    }
+}
+
+impl From<SystemTime> for EarthTime {
+    fn from(time: SystemTime) -> Self {
+        Self::from_epoch(epoch_from_system_time(time))
+    }
+}
+
+impl From<EarthTime> for Epoch {
+    fn from(time: EarthTime) -> Self {
+        time.epoch
+    }
+}
+
+impl From<EarthTime> for MarsInstant {
+    fn from(time: EarthTime) -> Self {
+        MarsInstant::from_epoch(time.epoch)
+    }
+}
+
+impl fmt::Display for EarthTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (year, month, day, hour, minute, mut second, nanos) =
+            self.epoch.to_gregorian_utc();
+
+        // UTC calendar repeats 23:59:59 during a leap second, the first one is the leap second
+        let repeated = Epoch::from_gregorian_utc(year, month, day, hour, minute, second, nanos)
+            .to_time_scale(TimeScale::TAI);
+        if second == 59 && repeated + Unit::Second == self.tai() {
+            second = 60;
+        }
+
+        write!(f, "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}", year, month, day, hour, minute, second)?;
+
+        if nanos > 0 {
+            let fraction = format!("{:09}", nanos);
+            write!(f, ".{}", fraction.trim_end_matches('0'))?;
+        }
+
+        write!(f, "Z")
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+/// Earth time inputs accepted by `to_msd`: ISO8601 / RFC3339 strings, `EarthTime`, hifitime
+/// `Epoch` and `SystemTime` values.
+pub trait ToEarthTime {
+    /// Returns the input as an `EarthTime`.
+    ///
+    /// # Errors
+    ///
+    /// - `TimeError::ISO8601FormatError`: If a string does not match the ISO8601 format.
+    /// - `TimeError::InvalidDateTimeField`: If a string has a datetime field out of range.
+    fn to_earth_time(&self) -> Result<EarthTime, TimeError>;
+}
+
+impl ToEarthTime for str {
+    fn to_earth_time(&self) -> Result<EarthTime, TimeError> {
+        EarthTime::from_utc(self)
+    }
+}
+
+impl ToEarthTime for String {
+    fn to_earth_time(&self) -> Result<EarthTime, TimeError> {
+        EarthTime::from_utc(self)
+    }
+}
+
+impl ToEarthTime for EarthTime {
+    fn to_earth_time(&self) -> Result<EarthTime, TimeError> {
+        Ok(*self)
+    }
+}
+
+impl ToEarthTime for Epoch {
+    fn to_earth_time(&self) -> Result<EarthTime, TimeError> {
+        Ok(EarthTime::from_epoch(*self))
+    }
+}
+
+impl ToEarthTime for SystemTime {
+    fn to_earth_time(&self) -> Result<EarthTime, TimeError> {
+        Ok(EarthTime::from(*self))
+    }
+}
+
+impl<T> ToEarthTime for &T
+    where T: ToEarthTime + ?Sized
+{
+    fn to_earth_time(&self) -> Result<EarthTime, TimeError> {
+        (**self).to_earth_time()
+    }
+}
+
+#[cfg(test)]
+mod earth_time_tests {
+    use super::*;
+
+    #[test]
+    fn test_rfc3339() {
+        let cases = [
+            ("2012-08-06T05:17:57Z", "2012-08-06T05:17:57Z"),
+            ("2012-08-06T07:17:57.5+02:00", "2012-08-06T05:17:57.5Z"),
+            ("2012-08-06T05:17:57.000000001", "2012-08-06T05:17:57.000000001Z"),
+            ("2016-12-31T23:59:59.25Z", "2016-12-31T23:59:59.25Z"),
+            ("2016-12-31T23:59:60.25Z", "2016-12-31T23:59:60.25Z"),
+            ("2017-01-01T00:00:00Z", "2017-01-01T00:00:00Z"),
+        ];
+
+        for (input, expected) in cases {
+            let time = EarthTime::from_utc(input).unwrap();
+
+            assert_eq!(time.to_rfc3339(), expected);
+            assert_eq!(EarthTime::from_utc(&time.to_rfc3339()).unwrap(), time);
+        }
+    }
+
+    #[test]
+    fn test_earth_time_scales() {
+        let time = EarthTime::from_utc("2012-08-06T05:17:57Z").unwrap();
+
+        assert_eq!(time.unix_seconds(), 1344230277.0);
+        assert_eq!(time.unix_nanoseconds(), 1_344_230_277_000_000_000);
+        assert!((time.jd_utc() - 2456145.72079861).abs() < 1.0e-8);
+        assert!((time.mjd_utc() - 56145.22079861).abs() < 1.0e-8);
+
+        // TAI was 35 s ahead of UTC and 16 s ahead of GPS time in 2012, TT is TAI + 32.184 s
+        assert_eq!(time.gps_seconds(), 1028265493.0);
+        assert_eq!(time.tai().to_gregorian_tai(), (2012, 8, 6, 5, 18, 32, 0));
+        let tt = time.tt().to_gregorian(TimeScale::TT);
+        assert_eq!(tt, (2012, 8, 6, 5, 19, 4, 184_000_000));
+        assert!((time.jde_tt() - (time.jd_utc() + 67.184 / 86400.0)).abs() < 1.0e-8);
+    }
+
+    #[test]
+    fn test_earth_time_constructors() {
+        let time = EarthTime::from_utc("2012-08-06T05:17:57Z").unwrap();
+        let tolerance = Unit::Microsecond * 100;
+
+        assert_eq!(EarthTime::from_unix_seconds(time.unix_seconds()).unwrap(), time);
+        assert_eq!(EarthTime::from_unix_nanoseconds(time.unix_nanoseconds()), time);
+        assert_eq!(EarthTime::from_gps_seconds(time.gps_seconds()).unwrap(), time);
+        assert_eq!(EarthTime::from(time.tai()), time);
+        assert_eq!(Epoch::from(time), time.epoch());
+
+        for other in [
+            EarthTime::from_jd_utc(time.jd_utc()).unwrap(),
+            EarthTime::from_mjd_utc(time.mjd_utc()).unwrap(),
+            EarthTime::from_jde_tt(time.jde_tt()).unwrap(),
+        ] {
+            assert!((other.epoch() - time.epoch()).abs() < tolerance, "{}", other);
+        }
+
+        let invalid = TimeError::InvalidArgumentError;
+        assert_eq!(EarthTime::from_unix_seconds(f64::NAN).unwrap_err(), invalid);
+        assert_eq!(EarthTime::from_jd_utc(f64::INFINITY).unwrap_err(), invalid);
+    }
+
+    #[test]
+    fn test_to_earth_time() {
+        let expected = EarthTime::from_utc("2012-08-06T05:17:57Z").unwrap();
+        let string = "2012-08-06T05:17:57Z".to_string();
+
+        assert_eq!("2012-08-06T05:17:57Z".to_earth_time().unwrap(), expected);
+        assert_eq!(string.to_earth_time().unwrap(), expected);
+        assert_eq!(ToEarthTime::to_earth_time(&&string).unwrap(), expected);
+        assert_eq!(expected.epoch().to_earth_time().unwrap(), expected);
+        let time = SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1344230277);
+        assert_eq!(time.to_earth_time().unwrap(), expected);
+        assert_eq!(EarthTime::from(time), expected);
+        assert_eq!("2012-08-06".to_earth_time().unwrap_err(), TimeError::ISO8601FormatError);
+    }
+}
@@ -1,8 +1,12 @@
+use std::fmt;
 use thiserror::Error;
 
 // ------------------------------------------------------------------------------------------------
 /// Errors that can occur while performing time-related operations.
+///
+/// New variants may be added in minor versions, so matches need a wildcard arm.
 #[derive(Error, Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TimeError {
     /// Unable to retrieve the current UTC time.
     #[error("Unable to retrieve the current UTC time.")]
@@ -19,4 +23,36 @@ pub enum TimeError {
     /// Provided date does not match the ISO8601 format. Eg. 2021-08-06T05:17:57.000
     #[error("Provided date does not match the ISO8601 format. Eg. 2021-08-06T05:17:57.000")]
     ISO8601FormatError,
+
+    /// A field of the provided ISO8601 datetime is out of range. Eg. 2021-02-30T00:00:00Z
+    #[error("Provided datetime has an invalid {0} field.")]
+    InvalidDateTimeField(DateTimeField),
+}
+
+// ------------------------------------------------------------------------------------------------
+/// Field of an ISO8601 datetime string reported by `TimeError::InvalidDateTimeField`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateTimeField {
+    Year,
+    Month,
+    Day,
+    Hour,
+    Minute,
+    Second,
+    Offset,
+}
+
+impl fmt::Display for DateTimeField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            DateTimeField::Year => "year",
+            DateTimeField::Month => "month",
+            DateTimeField::Day => "day",
+            DateTimeField::Hour => "hour",
+            DateTimeField::Minute => "minute",
+            DateTimeField::Second => "second",
+            DateTimeField::Offset => "UTC offset",
+        };
+        write!(f, "{}", name)
+    }
 }
@@ -1,9 +1,18 @@
-use crate::{ ISO8601_REGEX };
-use crate::time::constants::{ JD_ON_SOL_ZERO, SOL_IN_EARTH_DAYS };
+use crate::time::constants::{
+    JD_J2000,
+    MARS_SEMI_MAJOR_AXIS_AU,
+    MARS_TROPICAL_YEAR_SOLS,
+    MARS_YEAR_ONE_START_MSD,
+    PERTURBER_AMPLITUDES,
+    PERTURBER_PERIODS,
+    PERTURBER_PHASES,
+};
+use crate::time::clock::*;
+use crate::time::earth_time::*;
 use crate::time::errors::*;
 use crate::time::structs::*;
-use hifitime::{ Epoch, Duration };
-use regex::Regex;
+use std::time::SystemTime;
+use hifitime::{ Duration, Epoch, TimeScale, Unit };
 
 // ------------------------------------------------------------------------------------------------
 /// Get current Sol (MSD) on Mars.
@@ -21,42 +30,69 @@ use regex::Regex;
 ///
 /// # Errors
 ///
-/// May propagate Errors from `utc_to_msd` if function fails.
-///
-/// - `TimeError::ISO8601FormatError`: If the provided date does not match the ISO8601 format.
-/// - `TimeError::InvalidArgumentError`: If the provided argument value is invalid (didn't pass validation).
 /// - `TimeError::UtcTimeUnavailable`: If the current UTC time cannot be retrieved.
 /// - `TimeError::TimeCalculationError`: If the calculated MSD is invalid or out of expected bounds.
-
 pub fn msd_now() -> Result<f64, TimeError> {
-    //  Get the current time in UTC
-    let utc_epoch = get_current_utc_time().ok_or(TimeError::UtcTimeUnavailable)?;
-
-    // Convert the UTC Epoch to a ISO8601 string
-    let iso = utc_epoch.to_isoformat();
-
-    // Convert UTC to MSD
-    let msd = utc_to_msd(&iso)?;
-
-    Ok(msd)
+    msd_now_with_clock(&SystemClock)
 }
 
 /// Alias for `msd_now()`.
 pub fn current_sol() -> Result<f64, TimeError> {
-    let msd = msd_now();
-    msd
+    msd_now()
+}
+
+/// Get current Sol (MSD) on Mars as read from the provided clock.
+///
+/// # Examples
+///
+/// ```
+/// use martian::time::{ msd_now_with_clock, FixedClock };
+///
+/// // Curiosity Rover landing
+/// let clock = FixedClock::from_utc("2012-08-06T05:17:57Z").unwrap();
+///
+/// match msd_now_with_clock(&clock) {
+///     Ok(msd) => println!("Mars Sol Date: {:.7}", msd),
+///     Err(e) => eprintln!("Error calculating MSD: {}", e),
+/// }
+/// ```
+///
+/// # Errors
+///
+/// - `TimeError::UtcTimeUnavailable`: If the clock can't provide the current time.
+/// - `TimeError::TimeCalculationError`: If the calculated MSD is invalid or out of expected bounds.
+pub fn msd_now_with_clock<C>(clock: &C) -> Result<f64, TimeError>
+    where C: Clock + ?Sized
+{
+    msd_now_with_clock_and_model(clock, &TimeModel::MARS24)
 }
 
-#[cfg(not(test))]
-fn get_current_utc_time() -> Option<Epoch> {
-    Epoch::now().ok()
+/// Same as `msd_now` under the provided time model.
+///
+/// # Errors
+///
+/// - `TimeError::UtcTimeUnavailable`: If the current UTC time cannot be retrieved.
+/// - `TimeError::TimeCalculationError`: If the calculated MSD is invalid or out of expected bounds.
+pub fn msd_now_with_model(model: &TimeModel) -> Result<f64, TimeError> {
+    msd_now_with_clock_and_model(&SystemClock, model)
+}
+
+/// Same as `msd_now_with_clock` under the provided time model.
+///
+/// # Errors
+///
+/// - `TimeError::UtcTimeUnavailable`: If the clock can't provide the current time.
+/// - `TimeError::TimeCalculationError`: If the calculated MSD is invalid or out of expected bounds.
+pub fn msd_now_with_clock_and_model<C>(clock: &C, model: &TimeModel) -> Result<f64, TimeError>
+    where C: Clock + ?Sized
+{
+    Ok(MarsNow::now_with_clock_and_model(clock, model)?.msd)
 }
 
+// Curiosity Rover landing time, shared by the `*_now_with_clock` tests
 #[cfg(test)]
-fn get_current_utc_time() -> Option<Epoch> {
-    // Curiosity Rover landing time
-    let time = Epoch::from_gregorian_utc(2012, 8, 6, 5, 17, 57, 0);
-    Some(time)
+fn curiosity_landing_clock() -> FixedClock {
+    FixedClock::new(Epoch::from_gregorian_utc(2012, 8, 6, 5, 17, 57, 0))
 }
 
 #[cfg(test)]
@@ -65,7 +101,7 @@ mod msd_now_tests {
 
     #[test]
     fn test_curiosity_mission_sol() {
-        let result = msd_now().unwrap();
+        let result = msd_now_with_clock(&curiosity_landing_clock()).unwrap();
 
         // Curiosity Rover Landing sol
         // Value taken from https://www.giss.nasa.gov/tools/mars24/
@@ -76,11 +112,51 @@ mod msd_now_tests {
             "MSD calculation is off for Curiosity mission Sol 0"
         );
     }
+
+    #[test]
+    fn test_msd_now() {
+        // Sol for 2024-11-07T17:58:40.000, so the system clock is past it
+        assert!(msd_now().unwrap() > 53626.0011);
+        assert!(current_sol().unwrap() >= msd_now_with_clock(&SystemClock).unwrap() - 1.0e-6);
+    }
+
+    #[test]
+    fn test_now_with_model() {
+        let clock = curiosity_landing_clock();
+        let legacy = TimeModel::ALLISON_1997;
+        let msd = utc_to_msd_with_model("2012-08-06T05:17:57Z", &legacy).unwrap();
+
+        assert_eq!(msd_now_with_clock_and_model(&clock, &legacy).unwrap(), msd);
+        let mtc = mtc_now_with_clock_and_model(&clock, &legacy).unwrap();
+        assert_eq!(mtc, msd_to_mtc(msd).unwrap());
+        assert_eq!(
+            lmst_now_with_clock_and_model(&clock, 137.4417, &legacy).unwrap(),
+            lmst(msd, 137.4417).unwrap()
+        );
+
+        let zone = MarsTimeZone::from_zone(9).unwrap();
+        let time = zone_time_now_with_clock_and_model(&clock, &zone, &legacy).unwrap();
+        assert_eq!(time, zone.msd_to_zone_time(msd).unwrap());
+
+        // Mars24 variants match the plain functions
+        let mars24 = TimeModel::MARS24;
+        assert_eq!(msd_now_with_clock_and_model(&clock, &mars24), msd_now_with_clock(&clock));
+        assert_eq!(mtc_now_with_clock_and_model(&clock, &mars24), mtc_now_with_clock(&clock));
+
+        assert!(msd_now_with_model(&legacy).unwrap() > msd);
+        assert!(mtc_now_with_model(&legacy).is_ok());
+        assert!(lmst_now_with_model(137.4417, &legacy).is_ok());
+        assert!(zone_time_now_with_model(&zone, &legacy).is_ok());
+    }
 }
 
 // ------------------------------------------------------------------------------------------------
 /// Convert UTC datetime to the Sol Date (MSD) on Mars. Requires an ISO8601 formatted datetime string as input.
 ///
+/// Accepts RFC3339 offsets (`Z`, `+02:00`), input without an offset is treated as UTC. Fractional
+/// seconds are kept up to nanoseconds and announced leap seconds (`23:59:60`) are supported.
+/// `to_msd` accepts `EarthTime`, hifitime `Epoch` and `SystemTime` values as well.
+///
 /// # Examples
 ///
 /// ```
@@ -99,60 +175,178 @@ mod msd_now_tests {
 /// This function may return the following errors:
 ///
 /// - `TimeError::ISO8601FormatError`: If the provided date does not match the ISO8601 format.
-/// - `TimeError::InvalidArgumentError`: If the provided argument value is invalid (didn't pass validation).
+/// - `TimeError::InvalidDateTimeField`: If a datetime field is out of range (eg. 30 February).
 /// - `TimeError::TimeCalculationError`: If the calculated MSD is invalid or out of expected bounds.
-
 pub fn utc_to_msd(datetime: &str) -> Result<f64, TimeError> {
-    let regex = Regex::new(ISO8601_REGEX).map_err(|_| TimeError::ISO8601FormatError)?;
-    let regex_result = regex.captures(datetime).ok_or(TimeError::ISO8601FormatError)?;
+    utc_to_msd_with_model(datetime, &TimeModel::MARS24)
+}
+
+/// Convert UTC datetime to the Sol Date (MSD) on Mars under the provided time model.
+///
+/// # Errors
+///
+/// - `TimeError::ISO8601FormatError`: If the provided date does not match the ISO8601 format.
+/// - `TimeError::InvalidDateTimeField`: If a datetime field is out of range (eg. 30 February).
+/// - `TimeError::TimeCalculationError`: If the calculated MSD is invalid or out of expected bounds.
+pub fn utc_to_msd_with_model(datetime: &str, model: &TimeModel) -> Result<f64, TimeError> {
+    to_msd_with_model(datetime, model)
+}
+
+/// Convert Earth time to the Sol Date (MSD) on Mars. Accepts the ISO8601 formatted datetime
+/// strings `utc_to_msd` does, as well as `EarthTime`, hifitime `Epoch` and `SystemTime` values,
+/// so pipelines don't have to go through strings. See `ToEarthTime`.
+///
+/// # Examples
+///
+/// ```
+/// use martian::time::{ to_msd, EarthTime };
+///
+/// let time = EarthTime::from_unix_seconds(1344230277.0).unwrap();
+/// assert_eq!(to_msd(&time).unwrap(), to_msd("2012-08-06T05:17:57Z").unwrap());
+/// ```
+///
+/// # Errors
+///
+/// - `TimeError::ISO8601FormatError`: If a string does not match the ISO8601 format.
+/// - `TimeError::InvalidDateTimeField`: If a string has a datetime field out of range.
+/// - `TimeError::TimeCalculationError`: If the calculated MSD is invalid or out of expected bounds.
+pub fn to_msd<T>(time: T) -> Result<f64, TimeError>
+    where T: ToEarthTime
+{
+    to_msd_with_model(time, &TimeModel::MARS24)
+}
+
+/// Same as `to_msd` under the provided time model.
+///
+/// # Errors
+///
+/// - `TimeError::ISO8601FormatError`: If a string does not match the ISO8601 format.
+/// - `TimeError::InvalidDateTimeField`: If a string has a datetime field out of range.
+/// - `TimeError::TimeCalculationError`: If the calculated MSD is invalid or out of expected bounds.
+pub fn to_msd_with_model<T>(time: T, model: &TimeModel) -> Result<f64, TimeError>
+    where T: ToEarthTime
+{
+    epoch_to_msd_with_model(time.to_earth_time()?.epoch(), model)
+}
 
-    let year = validate_regex_value(regex_result.get(1), 0, i32::MAX)?; // Didn't set min to MIN_SOL_YEAR to get more meaningfull error when validating whole YYYY-MM-DD
-    let month = validate_regex_value(regex_result.get(2), 1, 12)?;
-    let day = validate_regex_value(regex_result.get(3), 1, 31)?; // TODO: Validate days per month
+// Parses an ISO8601 / RFC3339 datetime string into an Epoch, input without offset is read as UTC.
+// Hand-written over the raw bytes so that hot loops over telemetry rows don't allocate.
+pub(crate) fn parse_utc_epoch(datetime: &str) -> Result<Epoch, TimeError> {
+    let bytes = datetime.as_bytes();
 
-    let hour = validate_regex_value(regex_result.get(4), 0, 23)?;
-    let minute = validate_regex_value(regex_result.get(5), 0, 59)?;
-    let second = validate_regex_value(regex_result.get(6), 0, 59)?;
+    // YYYY-MM-DDTHH:MM:SS is the shortest accepted input
+    let (date_time, rest) = bytes.split_at_checked(19).ok_or(TimeError::ISO8601FormatError)?;
+    let [
+        y0, y1, y2, y3, b'-', mo0, mo1, b'-', d0, d1,
+        b'T' | b't' | b' ',
+        h0, h1, b':', mi0, mi1, b':', s0, s1
+    ] = *date_time else {
+        return Err(TimeError::ISO8601FormatError);
+    };
 
-    // If milliseconds are not present, default to 0
-    let millisecond = regex_result.get(7).map_or(0, |m| m.as_str().parse::<u32>().unwrap_or(0));
+    // Optional fraction of a second, "." or "," followed by at least one digit
+    let (fraction, offset) = match rest {
+        [b'.' | b',', tail @ ..] => {
+            let digits = tail.iter().take_while(|byte| byte.is_ascii_digit()).count();
+            if digits == 0 {
+                return Err(TimeError::ISO8601FormatError);
+            }
+            tail.split_at(digits)
+        }
+        _ => (&[][..], rest),
+    };
 
-    // Convert the provided date and time to an UTC Epoch
-    let utc = Epoch::from_gregorian_utc(year, month, day, hour, minute, second, millisecond);
+    // Offset of the local time from UTC, "Z" or no offset at all mean UTC
+    let (negative, offset_hours, offset_minutes) = match *offset {
+        [] | [b'Z' | b'z'] => (false, [b'0', b'0'], [b'0', b'0']),
+        [sign @ (b'+' | b'-'), h0, h1] => (sign == b'-', [h0, h1], [b'0', b'0']),
+        [sign @ (b'+' | b'-'), h0, h1, m0, m1] | [sign @ (b'+' | b'-'), h0, h1, b':', m0, m1] => {
+            (sign == b'-', [h0, h1], [m0, m1])
+        }
+        _ => return Err(TimeError::ISO8601FormatError),
+    };
 
-    // MSD = (JD_TDB - JD_ON_SOL_ZERO) / SOL_IN_EARTH_DAYS
-    let jde_tt = utc.to_jde_tt_days();
-    let msd: f64 = (jde_tt - JD_ON_SOL_ZERO) / SOL_IN_EARTH_DAYS;
+    // Whole input has the right shape, only now check the ranges so errors name the field
+    let year = parse_field(&[y0, y1, y2, y3], 0, 9999, DateTimeField::Year)? as i32;
+    let month = parse_field(&[mo0, mo1], 1, 12, DateTimeField::Month)? as u8;
+    let days = u32::from(days_in_month(year, month));
+    let day = parse_field(&[d0, d1], 1, days, DateTimeField::Day)? as u8;
 
-    if msd.is_finite() {
-        return Ok(msd);
+    let hour = parse_field(&[h0, h1], 0, 23, DateTimeField::Hour)? as u8;
+    let minute = parse_field(&[mi0, mi1], 0, 59, DateTimeField::Minute)? as u8;
+    // 60 is only kept if it lands on an announced leap second, checked below
+    let second = parse_field(&[s0, s1], 0, 60, DateTimeField::Second)? as u8;
+
+    // Fraction digits past nanoseconds are truncated
+    let nanos = fraction_to_nanos(fraction);
+
+    let offset_hours = parse_field(&offset_hours, 0, 23, DateTimeField::Offset)?;
+    let offset_minutes = parse_field(&offset_minutes, 0, 59, DateTimeField::Offset)?;
+    let offset_seconds = i64::from(offset_hours * 3600 + offset_minutes * 60);
+    let offset_seconds = if negative { -offset_seconds } else { offset_seconds };
+
+    // Shift the wall clock to UTC without the seconds, so a leap second survives the offset
+    let (year, month, day, hour, minute) = if offset_seconds == 0 {
+        (year, month, day, hour, minute)
     } else {
-        return Err(TimeError::TimeCalculationError);
+        let local = Epoch::from_gregorian(year, month, day, hour, minute, 0, 0, TimeScale::TAI);
+        let (year, month, day, hour, minute, _, _) =
+            (local - Unit::Second * offset_seconds).to_gregorian(TimeScale::TAI);
+        (year, month, day, hour, minute)
+    };
+
+    if second < 60 {
+        return Ok(Epoch::from_gregorian_utc(year, month, day, hour, minute, second, nanos));
     }
+
+    // hifitime knows the leap second table, but folds "23:59:60" onto "23:59:59"
+    Epoch::maybe_from_gregorian_utc(year, month, day, hour, minute, 60, 0)
+        .map_err(|_| TimeError::InvalidDateTimeField(DateTimeField::Second))?;
+    let last_second = Epoch::from_gregorian_utc(year, month, day, hour, minute, 59, nanos);
+
+    Ok(last_second.to_time_scale(TimeScale::TAI) + Unit::Second * 1)
 }
 
-fn validate_regex_value<T: std::str::FromStr>(
-    input: Option<regex::Match>,
-    min: T,
-    max: T
-) -> Result<T, TimeError>
-    where T: PartialOrd
-{
-    let value = input
-        .ok_or(TimeError::InvalidArgumentError)?
-        .as_str()
-        .parse::<T>()
-        .map_err(|_| TimeError::InvalidArgumentError)?;
-    if value >= min && value <= max {
+// Reads a fixed width run of ASCII digits and checks it against the field range
+fn parse_field(digits: &[u8], min: u32, max: u32, field: DateTimeField) -> Result<u32, TimeError> {
+    let mut value: u32 = 0;
+    for digit in digits {
+        if !digit.is_ascii_digit() {
+            return Err(TimeError::ISO8601FormatError);
+        }
+        value = value * 10 + u32::from(digit - b'0');
+    }
+
+    if (min..=max).contains(&value) {
         Ok(value)
     } else {
-        Err(TimeError::InvalidArgumentError)
+        Err(TimeError::InvalidDateTimeField(field))
+    }
+}
+
+// Converts the digits after the decimal separator to nanoseconds, eg. "5" -> 500_000_000
+fn fraction_to_nanos(digits: &[u8]) -> u32 {
+    digits
+        .iter()
+        .chain(std::iter::repeat(&b'0'))
+        .take(9)
+        .fold(0, |nanos, digit| nanos * 10 + u32::from(digit - b'0'))
+}
+
+// Number of days in a month of the Gregorian calendar
+fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        2 if (year % 4 == 0 && year % 100 != 0) || year % 400 == 0 => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
     }
 }
 
 #[cfg(test)]
 mod utc_to_msd_tests {
     use super::*;
+    use crate::time::constants::SOL_IN_EARTH_DAYS;
 
     #[test]
     fn test_utc_to_msd_success() {
@@ -208,12 +402,217 @@ mod utc_to_msd_tests {
         let date_time = "2021-13-29T00:00:00.000";
         let result = utc_to_msd(date_time);
 
-        assert_eq!(result.unwrap_err(), TimeError::InvalidArgumentError);
+        assert_eq!(result.unwrap_err(), TimeError::InvalidDateTimeField(DateTimeField::Month));
+    }
+
+    #[test]
+    fn test_utc_to_msd_invalid_fields() {
+        let cases = [
+            ("2021-02-29T00:00:00Z", DateTimeField::Day),
+            ("2021-04-31T00:00:00Z", DateTimeField::Day),
+            ("2021-01-01T24:00:00Z", DateTimeField::Hour),
+            ("2021-01-01T00:60:00Z", DateTimeField::Minute),
+            ("2021-01-01T00:00:61Z", DateTimeField::Second),
+            ("2021-01-01T00:00:00+24:00", DateTimeField::Offset),
+            ("2021-01-01T00:00:00+02:60", DateTimeField::Offset),
+        ];
+
+        for (date_time, field) in cases {
+            assert_eq!(
+                utc_to_msd(date_time).unwrap_err(),
+                TimeError::InvalidDateTimeField(field),
+                "{}",
+                date_time
+            );
+        }
+
+        // 2024 is a leap year, 1900 is not
+        assert!(utc_to_msd("2024-02-29T00:00:00Z").is_ok());
+        assert!(utc_to_msd("1900-02-29T00:00:00Z").is_err());
+    }
+
+    #[test]
+    fn test_utc_to_msd_malformed_input() {
+        for date_time in [
+            "2021-01-01T00:00:00.000abc",
+            "2021-01-01T00:00:00+02:00:00",
+            "2021-01-01T00:00:00+02:",
+            "2021-01-01T00:00:00.",
+            "2021-1-01T00:00:00",
+            "2021-01-01T00:00",
+            "2021-01-01X00:00:00",
+            "२०२१-01-01T00:00:00",
+            "",
+        ] {
+            assert_eq!(utc_to_msd(date_time).unwrap_err(), TimeError::ISO8601FormatError);
+        }
+    }
+
+    #[test]
+    fn test_utc_to_msd_offsets() {
+        let utc = utc_to_msd("2012-08-06T05:17:57.000").unwrap();
+
+        for date_time in [
+            "2012-08-06T05:17:57Z",
+            "2012-08-06t05:17:57z",
+            "2012-08-06 05:17:57+00:00",
+            "2012-08-06T07:17:57+02:00",
+            "2012-08-06T07:17:57+0200",
+            "2012-08-06T07:17:57+02",
+            "2012-08-05T23:47:57-05:30",
+        ] {
+            assert_eq!(utc_to_msd(date_time).unwrap(), utc, "{}", date_time);
+        }
+    }
+
+    #[test]
+    fn test_utc_to_msd_fractional_seconds() {
+        let whole = utc_to_msd("2012-08-06T05:17:57Z").unwrap();
+        let half = utc_to_msd("2012-08-06T05:17:57.5Z").unwrap();
+        let seconds_per_sol = SOL_IN_EARTH_DAYS * 86400.0;
+
+        assert!(((half - whole) * seconds_per_sol - 0.5).abs() < 1e-3);
+        assert_eq!(utc_to_msd("2012-08-06T05:17:57,5Z").unwrap(), half);
+
+        let whole = parse_utc_epoch("2012-08-06T05:17:57Z").unwrap();
+        let nanos = parse_utc_epoch("2012-08-06T05:17:57.000000001Z").unwrap();
+        assert_eq!(nanos - whole, Unit::Nanosecond * 1);
+
+        let epoch = parse_utc_epoch("2012-08-06T05:17:57.123456789Z").unwrap();
+        let expected = Epoch::from_gregorian_utc(2012, 8, 6, 5, 17, 57, 123_456_789);
+        assert_eq!(epoch, expected);
+
+        // Digits past nanoseconds are truncated
+        let epoch = parse_utc_epoch("2012-08-06T05:17:57.1234567899Z").unwrap();
+        assert_eq!(epoch, expected);
+    }
+
+    #[test]
+    fn test_utc_to_msd_leap_seconds() {
+        let before = parse_utc_epoch("2016-12-31T23:59:59.5Z").unwrap();
+        let leap = parse_utc_epoch("2016-12-31T23:59:60.5Z").unwrap();
+        let after = parse_utc_epoch("2017-01-01T00:00:00.5Z").unwrap();
+
+        assert_eq!(leap - before, Unit::Second * 1);
+        assert_eq!(after - leap, Unit::Second * 1);
+
+        // Same leap second seen from a +01:00 wall clock
+        assert_eq!(parse_utc_epoch("2017-01-01T00:59:60.5+01:00").unwrap(), leap);
+
+        // No leap second was inserted at the end of 2015
+        assert_eq!(
+            utc_to_msd("2015-12-31T23:59:60Z").unwrap_err(),
+            TimeError::InvalidDateTimeField(DateTimeField::Second)
+        );
+        assert_eq!(
+            utc_to_msd("2016-12-31T12:59:60Z").unwrap_err(),
+            TimeError::InvalidDateTimeField(DateTimeField::Second)
+        );
+    }
+
+    #[test]
+    fn test_utc_to_msd_deref_strings() {
+        use std::borrow::Cow;
+
+        // Callers holding other string types rely on deref coercion to `&str`
+        let date_time = "2012-08-06T05:17:57Z";
+        let cow: Cow<str> = Cow::Borrowed(date_time);
+        let boxed: Box<str> = date_time.into();
+        let owned = String::from(date_time);
+        let msd = utc_to_msd(date_time).unwrap();
+
+        assert_eq!(utc_to_msd(&cow).unwrap(), msd);
+        assert_eq!(utc_to_msd(&boxed).unwrap(), msd);
+        assert_eq!(utc_to_msd(&owned).unwrap(), msd);
+        assert_eq!(to_msd(date_time).unwrap(), msd);
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+/// Convert a hifitime `Epoch` in any time scale to the Sol Date (MSD) on Mars, without formatting
+/// and parsing a datetime string.
+///
+/// # Examples
+///
+/// ```
+/// use hifitime::Epoch;
+/// use martian::time::epoch_to_msd;
+///
+/// let epoch = Epoch::from_gregorian_utc_hms(2012, 8, 6, 5, 17, 57);
+/// println!("Mars Sol Date: {:.7}", epoch_to_msd(epoch).unwrap());
+/// ```
+///
+/// # Errors
+///
+/// - `TimeError::TimeCalculationError`: If the calculated MSD is invalid or out of expected bounds.
+pub fn epoch_to_msd(epoch: Epoch) -> Result<f64, TimeError> {
+    epoch_to_msd_with_model(epoch, &TimeModel::MARS24)
+}
+
+/// Convert a hifitime `Epoch` in any time scale to the Sol Date (MSD) on Mars under the provided
+/// time model.
+///
+/// # Errors
+///
+/// - `TimeError::TimeCalculationError`: If the calculated MSD is invalid or out of expected bounds.
+pub fn epoch_to_msd_with_model(epoch: Epoch, model: &TimeModel) -> Result<f64, TimeError> {
+    // MSD = (JD - JD_ON_SOL_ZERO) / SOL_IN_EARTH_DAYS, with JD_TT for Mars24. Counted in
+    // nanoseconds so the only rounding is the final conversion to f64.
+    let msd: f64 = MarsSolDate::from_epoch_with_model(epoch, model).to_msd_with_model(model);
+
+    if msd.is_finite() {
+        Ok(msd)
+    } else {
+        Err(TimeError::TimeCalculationError)
+    }
+}
+
+// Converts a system time to an Epoch, including times before the Unix epoch
+pub(crate) fn epoch_from_system_time(time: SystemTime) -> Epoch {
+    match time.duration_since(SystemTime::UNIX_EPOCH) {
+        Ok(since) => Epoch::from_unix_duration(since.into()),
+        Err(before) => Epoch::from_unix_duration(-Duration::from(before.duration())),
+    }
+}
+
+#[cfg(test)]
+mod epoch_to_msd_tests {
+    use super::*;
+    use std::time::Duration as StdDuration;
+
+    #[test]
+    fn test_epoch_to_msd() {
+        let utc = Epoch::from_gregorian_utc_hms(2012, 8, 6, 5, 17, 57);
+        let msd = utc_to_msd("2012-08-06T05:17:57Z").unwrap();
+
+        assert_eq!(epoch_to_msd(utc).unwrap(), msd);
+        assert_eq!(epoch_to_msd(utc.to_time_scale(TimeScale::TT)).unwrap(), msd);
+        assert_eq!(epoch_to_msd(utc.to_time_scale(TimeScale::GPST)).unwrap(), msd);
+
+        let legacy = TimeModel::ALLISON_1997;
+        assert_eq!(
+            epoch_to_msd_with_model(utc, &legacy).unwrap(),
+            utc_to_msd_with_model("2012-08-06T05:17:57Z", &legacy).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_epoch_from_system_time() {
+        let after = SystemTime::UNIX_EPOCH + StdDuration::new(1344230277, 250_000_000);
+        let before = SystemTime::UNIX_EPOCH - StdDuration::new(86400, 500_000_000);
+
+        let expected_after = parse_utc_epoch("2012-08-06T05:17:57.25").unwrap();
+        let expected_before = parse_utc_epoch("1969-12-30T23:59:59.5").unwrap();
+
+        assert_eq!(epoch_from_system_time(after), expected_after);
+        assert_eq!(epoch_from_system_time(before), expected_before);
+        assert_eq!(epoch_from_system_time(SystemTime::UNIX_EPOCH).to_unix_seconds(), 0.0);
     }
 }
 
 // ------------------------------------------------------------------------------------------------
-/// Convert Mars Sol Date (MSD) to UTC datetime. Returns an ISO8601 formatted datetime string.
+/// Convert Mars Sol Date (MSD) to UTC datetime. Returns an ISO8601 formatted datetime string,
+/// kept as a `String` for compatibility. `msd_to_earth_time` returns a structured `EarthTime`.
 ///
 /// # Examples
 ///
@@ -231,17 +630,115 @@ mod utc_to_msd_tests {
 /// # Note
 ///
 /// Dates before 1972 will not account for leap seconds.
-
+///
+/// # Errors
+///
+/// - `TimeError::InvalidArgumentError`: If the MSD is not finite.
+/// - `TimeError::TimeCalculationError`: If the MSD is outside the range of a hifitime `Epoch`.
 pub fn msd_to_utc(msd: f64) -> Result<String, TimeError> {
-    let jd_tdb = msd * SOL_IN_EARTH_DAYS + JD_ON_SOL_ZERO;
+    Ok(instant_to_utc(MarsInstant::from_msd(msd)?))
+}
+
+/// Convert a `MarsInstant`, or anything convertible into one such as a hifitime `Epoch` or
+/// `SystemTime`, to UTC datetime. Returns an ISO8601 formatted datetime string.
+///
+/// # Examples
+///
+/// ```
+/// use martian::time::{ instant_to_utc, MarsInstant };
+///
+/// let instant = MarsInstant::from_msd(49269.25).unwrap();
+/// println!("UTC: {}", instant_to_utc(instant));
+/// ```
+pub fn instant_to_utc<I>(instant: I) -> String
+    where I: Into<MarsInstant>
+{
+    instant.into().epoch().to_time_scale(TimeScale::UTC).to_isoformat()
+}
+
+/// Convert Mars Sol Date (MSD) to UTC datetime under the provided time model. Returns an ISO8601
+/// formatted datetime string.
+///
+/// # Note
+///
+/// Dates before 1972 will not account for leap seconds.
+///
+/// # Errors
+///
+/// - `TimeError::InvalidArgumentError`: If the MSD is not finite.
+/// - `TimeError::TimeCalculationError`: If the MSD is outside the range of a hifitime `Epoch`.
+pub fn msd_to_utc_with_model(msd: f64, model: &TimeModel) -> Result<String, TimeError> {
+    Ok(msd_to_epoch_with_model(msd, model)?.to_isoformat())
+}
+
+// ------------------------------------------------------------------------------------------------
+/// Convert Mars Sol Date (MSD) to a hifitime `Epoch` in UTC, without formatting and parsing a
+/// datetime string.
+///
+/// # Examples
+///
+/// ```
+/// use martian::time::{ epoch_to_msd, msd_to_epoch };
+///
+/// let epoch = msd_to_epoch(49269.25).unwrap();
+///
+/// println!("UTC: {}", epoch);
+/// assert!((epoch_to_msd(epoch).unwrap() - 49269.25).abs() < 1.0e-9);
+/// ```
+///
+/// # Errors
+///
+/// - `TimeError::InvalidArgumentError`: If the MSD is not finite.
+/// - `TimeError::TimeCalculationError`: If the MSD is outside the range of a hifitime `Epoch`.
+pub fn msd_to_epoch(msd: f64) -> Result<Epoch, TimeError> {
+    msd_to_epoch_with_model(msd, &TimeModel::MARS24)
+}
 
-    let tt_epoch = Epoch::from_jde_tdb(jd_tdb);
+/// Convert Mars Sol Date (MSD) to a hifitime `Epoch` in UTC under the provided time model.
+///
+/// # Errors
+///
+/// - `TimeError::InvalidArgumentError`: If the MSD is not finite.
+/// - `TimeError::TimeCalculationError`: If the MSD is outside the range of a hifitime `Epoch`.
+pub fn msd_to_epoch_with_model(msd: f64, model: &TimeModel) -> Result<Epoch, TimeError> {
+    let epoch = MarsSolDate::from_msd_with_model(msd, model)?.to_epoch_with_model(model);
+    Ok(epoch.to_time_scale(TimeScale::UTC))
+}
 
-    // Leap seconds or 0 if before 1972
-    let leap_seconds = tt_epoch.leap_seconds(true).unwrap_or(0.0);
-    let utc_epoch = tt_epoch - Duration::from_seconds(leap_seconds);
+// ------------------------------------------------------------------------------------------------
+/// Convert Mars Sol Date (MSD) to an `EarthTime`, which converts to RFC3339, Unix time, Julian
+/// Date, MJD, GPS time, TAI and TT without going through strings.
+///
+/// # Examples
+///
+/// ```
+/// use martian::time::{ msd_to_earth_time, to_msd };
+///
+/// let time = msd_to_earth_time(49269.25).unwrap();
+///
+/// println!("{} (Unix {:.3}, MJD {:.6})", time.to_rfc3339(), time.unix_seconds(), time.mjd_utc());
+/// assert!((to_msd(&time).unwrap() - 49269.25).abs() < 1.0e-9);
+/// ```
+///
+/// # Errors
+///
+/// - `TimeError::InvalidArgumentError`: If the MSD is not finite.
+/// - `TimeError::TimeCalculationError`: If the MSD is outside the range of a hifitime `Epoch`.
+pub fn msd_to_earth_time(msd: f64) -> Result<EarthTime, TimeError> {
+    msd_to_earth_time_with_model(msd, &TimeModel::MARS24)
+}
 
-    return Ok(utc_epoch.to_isoformat());
+/// Convert Mars Sol Date (MSD) to an `EarthTime` under the provided time model.
+///
+/// # Errors
+///
+/// - `TimeError::InvalidArgumentError`: If the MSD is not finite.
+/// - `TimeError::TimeCalculationError`: If the MSD is outside the range of a hifitime `Epoch`.
+pub fn msd_to_earth_time_with_model(
+    msd: f64,
+    model: &TimeModel
+) -> Result<EarthTime, TimeError> {
+    Ok(EarthTime::from_epoch(msd_to_epoch_with_model(msd, model)?))
 }
 
 #[cfg(test)]
@@ -291,62 +788,414 @@ mod msd_to_utc_tests {
 
         assert!(difference <= 1.0, "Difference is more than 1 second: {} seconds", difference);
     }
-}
 
-// ------------------------------------------------------------------------------------------------
-/// Get current Martian Coordinated Time (MTC) on Mars.
-///
-/// # Returns
-/// * `Result<Time, TimeError>` - The MTC time as a `Time` struct on a 24-hour Martian clock
-///
-/// ```
-/// use martian::time::mtc_now;
-///
-/// match mtc_now() {
-///     Ok(mtc) => println!("Mars Coordinated Time: {}", mtc),
-///     Err(e) => eprintln!("Error calculating MTC: {}", e),
-/// }
-/// ```
-///
-/// # Errors
-///
-/// May propagate Errors from `msd_now` if function fails.
-///
-/// - `TimeError::ISO8601FormatError`: If the provided date does not match the ISO8601 format.
-/// - `TimeError::InvalidArgumentError`: If the provided argument value is invalid (didn't pass validation).
-/// - `TimeError::UtcTimeUnavailable`: If the current UTC time cannot be retrieved.
-/// - `TimeError::TimeCalculationError`: If the calculated MSD is invalid or out of expected bounds.
+    #[test]
+    fn test_utc_to_msd_and_back_across_centuries() {
+        // ISO8601 output is truncated to microseconds and f64 MSD adds up to a microsecond more
+        for date_time in [
+            "1650-03-01T06:00:00.250000",
+            "1873-12-29T12:04:08.650560",
+            "2000-01-06T00:00:00.000001",
+            "2024-11-07T17:58:40.123456",
+            "2399-12-31T23:59:59.999999",
+        ] {
+            let msd = utc_to_msd(date_time).unwrap();
+            let result = parse_utc_epoch(&msd_to_utc(msd).unwrap()).unwrap();
+            let expected = parse_utc_epoch(date_time).unwrap();
 
-pub fn mtc_now() -> Result<Time, TimeError> {
-    // Get current sol
-    let msd = msd_now()?;
+            assert!((result - expected).abs() <= Unit::Microsecond * 2, "{}", date_time);
+        }
 
-    // MTC = (24 * MSD) % 24
-    let mtc_hours = (24.0 * msd) % 24.0;
+        assert_eq!(msd_to_utc(f64::NAN).unwrap_err(), TimeError::InvalidArgumentError);
+        assert_eq!(msd_to_utc(1.0e20).unwrap_err(), TimeError::TimeCalculationError);
+    }
 
-    // Extract hours, minutes, seconds, and milliseconds
-    let hours = mtc_hours;
-    let minutes = (mtc_hours % 1.0) * 60.0;
-    let seconds = ((mtc_hours * 60.0) % 1.0) * 60.0;
-    let milliseconds = (seconds % 1.0) * 1000.0;
+    #[test]
+    fn test_instant_to_utc() {
+        let instant = MarsInstant::from_msd(53626.0011).unwrap();
 
-    Ok(
-        Time::new(
-            hours.floor() as u32,
-            minutes.floor() as u32,
-            seconds.floor() as u32,
-            milliseconds.round() as u32
-        )
-    )
-}
+        assert_eq!(instant_to_utc(instant), msd_to_utc(53626.0011).unwrap());
+        assert_eq!(instant_to_utc(instant.epoch()), instant.utc());
+        assert_eq!(instant_to_utc(EarthTime::from_epoch(instant.epoch())), instant.utc());
+    }
 
-#[cfg(test)]
-mod mtc_now_tests {
+    #[test]
+    fn test_msd_to_epoch() {
+        let expected = parse_utc_epoch("2024-11-07T17:58:40.123456789Z").unwrap();
+        let epoch = msd_to_epoch(epoch_to_msd(expected).unwrap()).unwrap();
+
+        assert!((epoch - expected).abs() <= Unit::Microsecond, "{}", epoch);
+        assert_eq!(epoch.time_scale, TimeScale::UTC);
+
+        let legacy = TimeModel::ALLISON_1997;
+        let epoch = msd_to_epoch_with_model(53626.0011, &legacy).unwrap();
+        assert!((epoch_to_msd_with_model(epoch, &legacy).unwrap() - 53626.0011).abs() < 1.0e-9);
+
+        assert_eq!(msd_to_epoch(f64::NAN).unwrap_err(), TimeError::InvalidArgumentError);
+    }
+
+    #[test]
+    fn test_msd_to_earth_time() {
+        let expected = parse_utc_epoch("2024-11-07T17:58:40.123456789Z").unwrap();
+        let msd = utc_to_msd("2024-11-07T17:58:40.123456789Z").unwrap();
+        let time = msd_to_earth_time(msd).unwrap();
+
+        assert!((time.epoch() - expected).abs() <= Unit::Microsecond, "{}", time);
+        assert_eq!(time.to_rfc3339(), time.to_string());
+        assert!(time.to_rfc3339().starts_with("2024-11-07T17:58:40.123"));
+        assert!(time.to_rfc3339().ends_with('Z'));
+
+        // Any EarthTime representation goes back to the same MSD without strings
+        let unix = EarthTime::from_unix_nanoseconds(time.unix_nanoseconds());
+        let direct = epoch_to_msd(time.epoch()).unwrap();
+        assert_eq!(to_msd(time).unwrap(), direct);
+        assert_eq!(to_msd(unix).unwrap(), to_msd(time).unwrap());
+        assert_eq!(to_msd(time.tt()).unwrap(), to_msd(time).unwrap());
+        assert_eq!(to_msd(time.to_rfc3339()).unwrap(), to_msd(time).unwrap());
+        assert_eq!(to_msd(time.to_rfc3339().as_str()).unwrap(), to_msd(time).unwrap());
+
+        let legacy = TimeModel::ALLISON_1997;
+        let time = msd_to_earth_time_with_model(msd, &legacy).unwrap();
+        assert!((to_msd_with_model(time, &legacy).unwrap() - msd).abs() < 1.0e-9);
+
+        assert_eq!(msd_to_earth_time(f64::NAN).unwrap_err(), TimeError::InvalidArgumentError);
+    }
+}
+
+#[cfg(test)]
+mod time_model_tests {
+    use super::*;
+    use crate::time::constants::SOL_IN_EARTH_DAYS;
+
+    const SECONDS_PER_SOL: f64 = SOL_IN_EARTH_DAYS * 86400.0;
+
+    const DATE_TIMES: [&str; 4] = [
+        "1873-12-29T12:00:00Z",
+        "2000-01-06T00:00:00Z",
+        "2012-08-06T05:17:57Z",
+        "2024-11-07T17:58:40Z",
+    ];
+
+    fn models() -> [TimeModel; 4] {
+        [
+            TimeModel::MARS24,
+            TimeModel::ALLISON_1997,
+            TimeModel::custom(1.0274912517, 2405522.0028779, JulianDateScale::Utc).unwrap(),
+            TimeModel::custom(1.0274912517, 2405522.0028779, JulianDateScale::Tdb).unwrap(),
+        ]
+    }
+
+    #[test]
+    fn test_mars24_is_default() {
+        for date_time in DATE_TIMES {
+            let msd = utc_to_msd(date_time).unwrap();
+
+            assert_eq!(utc_to_msd_with_model(date_time, &TimeModel::default()).unwrap(), msd);
+            let utc = msd_to_utc_with_model(msd, &TimeModel::MARS24).unwrap();
+            assert_eq!(utc, msd_to_utc(msd).unwrap());
+
+            let ls = msd_to_ls_with_model(msd, &TimeModel::MARS24).unwrap();
+            assert_eq!(ls, msd_to_ls(msd).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_allison_1997() {
+        // MSD = (JD_TT - 2451549.5) / 1.02749125 + 44796.0 - 0.00072, TT - UTC was 64.184 s
+        let result = utc_to_msd_with_model("2000-01-06T00:00:00Z", &TimeModel::ALLISON_1997);
+        let expected = 64.184 / 86400.0 / 1.02749125 + 44796.0 - 0.00072;
+        assert!((result.unwrap() - expected).abs() < 1.0e-8);
+
+        // Offsets of the two models are about 20 seconds apart at the Curiosity landing
+        let mars24 = utc_to_msd("2012-08-06T05:17:57Z").unwrap();
+        let allison = utc_to_msd_with_model("2012-08-06T05:17:57Z", &TimeModel::ALLISON_1997);
+        let difference = (allison.unwrap() - mars24) * SECONDS_PER_SOL;
+        assert!(difference > 15.0 && difference < 30.0, "{}", difference);
+    }
+
+    #[test]
+    fn test_julian_date_scales() {
+        let [mars24, _, utc, tdb] = models();
+
+        // TT was ahead of UTC by 67.184 s in 2012
+        let date_time = "2012-08-06T05:17:57Z";
+        let msd = utc_to_msd_with_model(date_time, &mars24).unwrap();
+        let result = utc_to_msd_with_model(date_time, &utc).unwrap();
+        assert!(((msd - result) * SECONDS_PER_SOL - 67.184).abs() < 1.0e-3);
+
+        // TDB stays within 2 ms of TT
+        let result = utc_to_msd_with_model(date_time, &tdb).unwrap();
+        assert!(((msd - result) * SECONDS_PER_SOL).abs() < 2.0e-3);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for model in models() {
+            for date_time in &DATE_TIMES[1..] {
+                let msd = utc_to_msd_with_model(date_time, &model).unwrap();
+                let utc = msd_to_utc_with_model(msd, &model).unwrap();
+
+                let expected = parse_utc_epoch(date_time).unwrap();
+                let difference = parse_utc_epoch(&utc).unwrap() - expected;
+                assert!(difference.abs() < Unit::Millisecond * 1, "{:?} {}", model, utc);
+            }
+        }
+    }
+
+    #[test]
+    fn test_model_dependent_functions() {
+        let model = TimeModel::ALLISON_1997;
+        let msd = utc_to_msd_with_model("2024-11-07T17:58:40Z", &model).unwrap();
+
+        assert_eq!(utc_to_mars_year_with_model("2024-11-07T17:58:40Z", &model).unwrap(), 37);
+        assert_eq!(msd_to_mars_year_with_model(msd, &model).unwrap(), 37);
+        assert_ne!(msd_to_ls_with_model(msd, &model).unwrap(), msd_to_ls(msd).unwrap());
+
+        // Same instant gives the same Ls whatever the model
+        let ls = utc_to_ls_with_model("2024-11-07T17:58:40Z", &model).unwrap();
+        assert!((ls - utc_to_ls("2024-11-07T17:58:40Z").unwrap()).abs() < 1.0e-9);
+
+        let msd = mars_year_ls_to_msd_with_model(38, 0.0, &model).unwrap();
+        assert!(msd_to_ls_with_model(msd, &model).unwrap().abs() < 1.0e-6);
+        let utc = mars_year_ls_to_utc_with_model(38, 0.0, &model).unwrap();
+        assert!(utc.starts_with("2024-11-12"), "{}", utc);
+    }
+
+    #[test]
+    fn test_custom_model_invalid_arguments() {
+        let cases = [(0.0, 0.0), (-1.0, 0.0), (f64::NAN, 0.0), (1.0, f64::INFINITY)];
+
+        for (sol, jd) in cases {
+            let result = TimeModel::custom(sol, jd, JulianDateScale::Tt);
+            assert_eq!(result.unwrap_err(), TimeError::InvalidArgumentError);
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+/// Get current Martian Coordinated Time (MTC) on Mars.
+///
+/// # Returns
+/// * `Result<Time, TimeError>` - The MTC time as a `Time` struct on a 24-hour Martian clock
+///
+/// ```
+/// use martian::time::mtc_now;
+///
+/// match mtc_now() {
+///     Ok(mtc) => println!("Mars Coordinated Time: {}", mtc),
+///     Err(e) => eprintln!("Error calculating MTC: {}", e),
+/// }
+/// ```
+///
+/// # Errors
+///
+/// May propagate Errors from `msd_now` if function fails.
+///
+/// - `TimeError::UtcTimeUnavailable`: If the current UTC time cannot be retrieved.
+/// - `TimeError::TimeCalculationError`: If the calculated MSD is invalid or out of expected bounds.
+pub fn mtc_now() -> Result<Time, TimeError> {
+    mtc_now_with_clock(&SystemClock)
+}
+
+/// Get current Martian Coordinated Time (MTC) on Mars as read from the provided clock.
+///
+/// # Examples
+///
+/// ```
+/// use martian::time::{ mtc_now_with_clock, FixedClock };
+///
+/// // Curiosity Rover landing
+/// let clock = FixedClock::from_utc("2012-08-06T05:17:57Z").unwrap();
+/// let mtc = mtc_now_with_clock(&clock).unwrap();
+///
+/// assert_eq!((mtc.hours, mtc.minutes, mtc.seconds), (5, 53, 28));
+/// ```
+///
+/// # Errors
+///
+/// - `TimeError::UtcTimeUnavailable`: If the clock can't provide the current time.
+/// - `TimeError::TimeCalculationError`: If the calculated MSD is invalid or out of expected bounds.
+pub fn mtc_now_with_clock<C>(clock: &C) -> Result<Time, TimeError>
+    where C: Clock + ?Sized
+{
+    mtc_now_with_clock_and_model(clock, &TimeModel::MARS24)
+}
+
+/// Same as `mtc_now` under the provided time model.
+///
+/// # Errors
+///
+/// - `TimeError::UtcTimeUnavailable`: If the current UTC time cannot be retrieved.
+/// - `TimeError::TimeCalculationError`: If the calculated MSD is invalid or out of expected bounds.
+pub fn mtc_now_with_model(model: &TimeModel) -> Result<Time, TimeError> {
+    mtc_now_with_clock_and_model(&SystemClock, model)
+}
+
+/// Same as `mtc_now_with_clock` under the provided time model.
+///
+/// # Errors
+///
+/// - `TimeError::UtcTimeUnavailable`: If the clock can't provide the current time.
+/// - `TimeError::TimeCalculationError`: If the calculated MSD is invalid or out of expected bounds.
+pub fn mtc_now_with_clock_and_model<C>(clock: &C, model: &TimeModel) -> Result<Time, TimeError>
+    where C: Clock + ?Sized
+{
+    Ok(MarsNow::now_with_clock_and_model(clock, model)?.mtc)
+}
+
+/// Get current local time in a Mars time zone.
+///
+/// # Examples
+///
+/// ```
+/// use martian::time::{ zone_time_now, MarsTimeZone };
+///
+/// // Zone of the Perseverance Rover at Jezero crater
+/// let zone = MarsTimeZone::for_longitude(77.4509).unwrap();
+///
+/// match zone_time_now(&zone) {
+///     Ok(time) => println!("{} {}", time, zone),
+///     Err(e) => eprintln!("Error calculating zone time: {}", e),
+/// }
+/// ```
+///
+/// # Errors
+///
+/// - `TimeError::UtcTimeUnavailable`: If the current UTC time cannot be retrieved.
+/// - `TimeError::TimeCalculationError`: If the calculated MSD is invalid or out of expected bounds.
+pub fn zone_time_now(zone: &MarsTimeZone) -> Result<Time, TimeError> {
+    zone_time_now_with_clock(&SystemClock, zone)
+}
+
+/// Get current local time in a Mars time zone as read from the provided clock.
+///
+/// # Errors
+///
+/// - `TimeError::UtcTimeUnavailable`: If the clock can't provide the current time.
+/// - `TimeError::TimeCalculationError`: If the calculated MSD is invalid or out of expected bounds.
+pub fn zone_time_now_with_clock<C>(clock: &C, zone: &MarsTimeZone) -> Result<Time, TimeError>
+    where C: Clock + ?Sized
+{
+    zone_time_now_with_clock_and_model(clock, zone, &TimeModel::MARS24)
+}
+
+/// Same as `zone_time_now` under the provided time model.
+///
+/// # Errors
+///
+/// - `TimeError::UtcTimeUnavailable`: If the current UTC time cannot be retrieved.
+/// - `TimeError::TimeCalculationError`: If the calculated MSD is invalid or out of expected bounds.
+pub fn zone_time_now_with_model(zone: &MarsTimeZone, model: &TimeModel) -> Result<Time, TimeError> {
+    zone_time_now_with_clock_and_model(&SystemClock, zone, model)
+}
+
+/// Same as `zone_time_now_with_clock` under the provided time model.
+///
+/// # Errors
+///
+/// - `TimeError::UtcTimeUnavailable`: If the clock can't provide the current time.
+/// - `TimeError::TimeCalculationError`: If the calculated MSD is invalid or out of expected bounds.
+pub fn zone_time_now_with_clock_and_model<C>(
+    clock: &C,
+    zone: &MarsTimeZone,
+    model: &TimeModel
+) -> Result<Time, TimeError>
+    where C: Clock + ?Sized
+{
+    Ok(MarsNow::now_with_clock_and_model(clock, model)?.zone_time(zone))
+}
+
+/// Convert Mars Sol Date (MSD) to Coordinated Mars Time (MTC).
+///
+/// # Examples
+///
+/// ```
+/// use martian::time::msd_to_mtc;
+///
+/// match msd_to_mtc(49269.2454) {
+///     Ok(mtc) => println!("Mars Coordinated Time: {}", mtc),
+///     Err(e) => eprintln!("Error calculating MTC: {}", e),
+/// }
+/// ```
+///
+/// # Errors
+///
+/// - `TimeError::InvalidArgumentError`: If the MSD is not finite.
+pub fn msd_to_mtc(msd: f64) -> Result<Time, TimeError> {
+    if !msd.is_finite() {
+        return Err(TimeError::InvalidArgumentError);
+    }
+
+    // MTC = (24 * MSD) % 24
+    Ok(sol_fraction_to_time(msd))
+}
+
+/// Convert UTC datetime to Coordinated Mars Time (MTC). Requires an ISO8601 formatted datetime
+/// string as input.
+///
+/// # Examples
+///
+/// ```
+/// use martian::time::utc_to_mtc;
+///
+/// match utc_to_mtc("2012-08-06T05:17:57.000") {
+///     Ok(mtc) => println!("Mars Coordinated Time: {}", mtc),
+///     Err(e) => eprintln!("Error calculating MTC: {}", e),
+/// }
+/// ```
+///
+/// # Errors
+///
+/// May propagate Errors from `utc_to_msd` if function fails.
+///
+/// - `TimeError::ISO8601FormatError`: If the provided date does not match the ISO8601 format.
+/// - `TimeError::InvalidDateTimeField`: If a datetime field is out of range (eg. 30 February).
+/// - `TimeError::TimeCalculationError`: If the calculated MSD is invalid or out of expected bounds.
+pub fn utc_to_mtc(datetime: &str) -> Result<Time, TimeError> {
+    let msd = utc_to_msd(datetime)?;
+    msd_to_mtc(msd)
+}
+
+/// Convert a sol number and Coordinated Mars Time (MTC) of that sol to Mars Sol Date (MSD).
+///
+/// # Arguments
+///
+/// * `sol` - Integer part of the MSD, the sol number at the Airy-0 prime meridian.
+/// * `mtc` - Coordinated Mars Time within that sol.
+///
+/// # Examples
+///
+/// ```
+/// use martian::time::{ mtc_to_msd, Time };
+///
+/// let msd = mtc_to_msd(49269, Time::new(6, 0, 0, 0)).unwrap();
+/// assert_eq!(msd, 49269.25);
+/// ```
+///
+/// # Errors
+///
+/// - `TimeError::InvalidArgumentError`: If any of the time components is out of range.
+pub fn mtc_to_msd(sol: i64, mtc: Time) -> Result<f64, TimeError> {
+    if mtc.hours > 23 || mtc.minutes > 59 || mtc.seconds > 59 || mtc.milliseconds > 999 {
+        return Err(TimeError::InvalidArgumentError);
+    }
+
+    let milliseconds =
+        (mtc.hours as u64) * 3_600_000 +
+        (mtc.minutes as u64) * 60_000 +
+        (mtc.seconds as u64) * 1000 +
+        (mtc.milliseconds as u64);
+
+    Ok((sol as f64) + (milliseconds as f64) / 86_400_000.0)
+}
+
+#[cfg(test)]
+mod mtc_now_tests {
     use super::*;
 
     #[test]
     fn test_mtc() {
-        let mtc = mtc_now().unwrap();
+        let mtc = mtc_now_with_clock(&curiosity_landing_clock()).unwrap();
 
         // Curiosity Rover Landing MTC - 5:53:28
         // Value taken from https://www.giss.nasa.gov/tools/mars24/
@@ -354,4 +1203,1039 @@ mod mtc_now_tests {
         assert!(mtc.minutes == 53, "MTC minutes are off for Curiosity mission Sol 0");
         assert!(mtc.seconds == 28, "MTC seconds are off for Curiosity mission Sol 0");
     }
+
+    #[test]
+    fn test_zone_time_now() {
+        let clock = curiosity_landing_clock();
+        let zone = MarsTimeZone::for_longitude(137.4417).unwrap();
+
+        // Curiosity Rover Landing MTC - 5:53:28, 9 Mars hours ahead at Gale crater
+        let time = zone_time_now_with_clock(&clock, &zone).unwrap();
+        assert_eq!((time.hours, time.minutes, time.seconds), (14, 53, 28));
+
+        let time = zone_time_now_with_clock(&clock, &MarsTimeZone::MTC).unwrap();
+        assert_eq!(time, mtc_now_with_clock(&clock).unwrap());
+
+        assert!(zone_time_now(&zone).is_ok());
+    }
+
+    #[test]
+    fn test_msd_to_mtc() {
+        assert_eq!(msd_to_mtc(49269.25).unwrap(), Time::new(6, 0, 0, 0));
+        assert_eq!(msd_to_mtc(-0.75).unwrap(), Time::new(6, 0, 0, 0));
+        let clock = curiosity_landing_clock();
+        let msd = msd_now_with_clock(&clock).unwrap();
+        assert_eq!(msd_to_mtc(msd).unwrap(), mtc_now_with_clock(&clock).unwrap());
+        assert_eq!(msd_to_mtc(f64::NAN).unwrap_err(), TimeError::InvalidArgumentError);
+    }
+
+    #[test]
+    fn test_utc_to_mtc() {
+        // Curiosity Rover Landing MTC - 5:53:28
+        let mtc = utc_to_mtc("2012-08-06T05:17:57.000").unwrap();
+        assert_eq!((mtc.hours, mtc.minutes, mtc.seconds), (5, 53, 28));
+
+        let result = utc_to_mtc("2021-13-29T00:00:00.000");
+        assert_eq!(result.unwrap_err(), TimeError::InvalidDateTimeField(DateTimeField::Month));
+    }
+
+    #[test]
+    fn test_mtc_to_msd() {
+        assert_eq!(mtc_to_msd(49269, Time::new(6, 0, 0, 0)).unwrap(), 49269.25);
+        assert_eq!(mtc_to_msd(-1, Time::new(18, 0, 0, 0)).unwrap(), -0.25);
+
+        let result = mtc_to_msd(49269, Time::new(24, 0, 0, 0));
+        assert_eq!(result.unwrap_err(), TimeError::InvalidArgumentError);
+
+        let result = mtc_to_msd(49269, Time::new(0, 0, 0, 1000));
+        assert_eq!(result.unwrap_err(), TimeError::InvalidArgumentError);
+    }
+
+    #[test]
+    fn test_mtc_to_msd_and_back() {
+        let msd = 53626.0011;
+        let mtc = msd_to_mtc(msd).unwrap();
+
+        let result = mtc_to_msd(msd.floor() as i64, mtc).unwrap();
+        assert!((result - msd).abs() < 1.0e-8, "MSD: {} != {}", result, msd);
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+/// Convert Mars Sol Date (MSD) to Local Mean Solar Time (LMST) at a given Mars longitude.
+///
+/// # Arguments
+///
+/// * `msd` - Martian Sol Date for which LMST should be calculated.
+/// * `east_longitude` - Planetocentric east longitude of the site in degrees (-360 to 360).
+///
+/// # Examples
+///
+/// ```
+/// use martian::time::lmst;
+///
+/// // Curiosity Rover landing at Gale crater
+/// let msd = 49269.25;
+/// let east_longitude = 137.4417;
+///
+/// match lmst(msd, east_longitude) {
+///     Ok(lmst) => println!("Local Mean Solar Time: {}", lmst),
+///     Err(e) => eprintln!("Error calculating LMST: {}", e),
+/// }
+/// ```
+///
+/// # Errors
+///
+/// - `TimeError::InvalidArgumentError`: If the MSD is not finite or the longitude is out of range.
+/// - `TimeError::TimeCalculationError`: If the MSD is outside the range of a hifitime `Epoch`.
+pub fn lmst(msd: f64, east_longitude: f64) -> Result<Time, TimeError> {
+    instant_to_lmst(MarsInstant::from_msd(msd)?, east_longitude)
+}
+
+/// Get Local Mean Solar Time (LMST) of a `MarsInstant`, or anything convertible into one such as
+/// a hifitime `Epoch` or `SystemTime`, at a given Mars longitude.
+///
+/// # Examples
+///
+/// ```
+/// use martian::time::{ instant_to_lmst, MarsInstant };
+///
+/// let instant = MarsInstant::from_utc("2012-08-06T05:17:57Z").unwrap();
+/// println!("Local Mean Solar Time: {}", instant_to_lmst(instant, 137.4417).unwrap());
+/// ```
+///
+/// # Errors
+///
+/// - `TimeError::InvalidArgumentError`: If the longitude is out of range.
+pub fn instant_to_lmst<I>(instant: I, east_longitude: f64) -> Result<Time, TimeError>
+    where I: Into<MarsInstant>
+{
+    let local_msd = local_msd(instant.into().msd(), east_longitude)?;
+
+    // LMST = MTC + east_longitude * (24h / 360°)
+    Ok(sol_fraction_to_time(local_msd))
+}
+
+/// Get current Local Mean Solar Time (LMST) at a given Mars longitude.
+///
+/// # Examples
+///
+/// ```
+/// use martian::time::lmst_now;
+///
+/// // Perseverance Rover at Jezero crater
+/// match lmst_now(77.4509) {
+///     Ok(lmst) => println!("Local Mean Solar Time: {}", lmst),
+///     Err(e) => eprintln!("Error calculating LMST: {}", e),
+/// }
+/// ```
+///
+/// # Errors
+///
+/// May propagate Errors from `msd_now` if function fails.
+///
+/// - `TimeError::InvalidArgumentError`: If the longitude is out of range.
+/// - `TimeError::UtcTimeUnavailable`: If the current UTC time cannot be retrieved.
+/// - `TimeError::TimeCalculationError`: If the calculated MSD is invalid or out of expected bounds.
+pub fn lmst_now(east_longitude: f64) -> Result<Time, TimeError> {
+    lmst_now_with_clock(&SystemClock, east_longitude)
+}
+
+/// Get current Local Mean Solar Time (LMST) at a given Mars longitude as read from the provided
+/// clock.
+///
+/// # Errors
+///
+/// - `TimeError::InvalidArgumentError`: If the longitude is out of range.
+/// - `TimeError::UtcTimeUnavailable`: If the clock can't provide the current time.
+/// - `TimeError::TimeCalculationError`: If the calculated MSD is invalid or out of expected bounds.
+pub fn lmst_now_with_clock<C>(clock: &C, east_longitude: f64) -> Result<Time, TimeError>
+    where C: Clock + ?Sized
+{
+    lmst_now_with_clock_and_model(clock, east_longitude, &TimeModel::MARS24)
+}
+
+/// Same as `lmst_now` under the provided time model.
+///
+/// # Errors
+///
+/// - `TimeError::InvalidArgumentError`: If the longitude is out of range.
+/// - `TimeError::UtcTimeUnavailable`: If the current UTC time cannot be retrieved.
+/// - `TimeError::TimeCalculationError`: If the calculated MSD is invalid or out of expected bounds.
+pub fn lmst_now_with_model(east_longitude: f64, model: &TimeModel) -> Result<Time, TimeError> {
+    lmst_now_with_clock_and_model(&SystemClock, east_longitude, model)
+}
+
+/// Same as `lmst_now_with_clock` under the provided time model.
+///
+/// # Errors
+///
+/// - `TimeError::InvalidArgumentError`: If the longitude is out of range.
+/// - `TimeError::UtcTimeUnavailable`: If the clock can't provide the current time.
+/// - `TimeError::TimeCalculationError`: If the calculated MSD is invalid or out of expected bounds.
+pub fn lmst_now_with_clock_and_model<C>(
+    clock: &C,
+    east_longitude: f64,
+    model: &TimeModel
+) -> Result<Time, TimeError>
+    where C: Clock + ?Sized
+{
+    MarsNow::now_with_clock_and_model(clock, model)?.lmst(east_longitude)
+}
+
+/// Get the local sol number at a given Mars longitude. Local sol changes at local midnight
+/// instead of midnight at the Airy-0 prime meridian.
+///
+/// # Examples
+///
+/// ```
+/// use martian::time::local_sol;
+///
+/// let sol = local_sol(49269.25, 137.4417).unwrap();
+/// assert_eq!(sol, 49269);
+/// ```
+///
+/// # Errors
+///
+/// - `TimeError::InvalidArgumentError`: If the MSD is not finite or the longitude is out of range.
+pub fn local_sol(msd: f64, east_longitude: f64) -> Result<i64, TimeError> {
+    Ok(local_msd(msd, east_longitude)?.floor() as i64)
+}
+
+// Shifts the MSD by the longitude offset of the site
+pub(crate) fn local_msd(msd: f64, east_longitude: f64) -> Result<f64, TimeError> {
+    if !msd.is_finite() || !east_longitude.is_finite() || east_longitude.abs() > 360.0 {
+        return Err(TimeError::InvalidArgumentError);
+    }
+
+    Ok(msd + normalize_longitude(east_longitude) / 360.0)
+}
+
+// Maps an east longitude to (-180°, 180°], so 270°E and 90°W shift the MSD by the same offset
+pub(crate) fn normalize_longitude(east_longitude: f64) -> f64 {
+    180.0 - (180.0 - east_longitude).rem_euclid(360.0)
+}
+
+// Converts the fraction of a sol into a `Time` on a 24-hour Martian clock. Truncated to whole
+// milliseconds, so the clock never shows midnight of a sol `sols.floor()` hasn't reached yet.
+// Values a few ULPs short of a millisecond boundary are floating point noise and count as on it.
+pub(crate) fn sol_fraction_to_time(sols: f64) -> Time {
+    let fraction = sols - sols.floor();
+    let tolerance = 4.0 * f64::EPSILON * sols.abs().max(1.0) * 86_400_000.0;
+    let total_milliseconds = (fraction * 86_400_000.0 + tolerance).floor() as u64;
+
+    milliseconds_to_time(total_milliseconds.min(86_399_999))
+}
+
+// Converts milliseconds since midnight into a `Time` on a 24-hour Martian clock
+pub(crate) fn milliseconds_to_time(total_milliseconds: u64) -> Time {
+    let total_milliseconds = total_milliseconds % 86_400_000;
+
+    Time::new(
+        (total_milliseconds / 3_600_000) as u32,
+        ((total_milliseconds / 60_000) % 60) as u32,
+        ((total_milliseconds / 1000) % 60) as u32,
+        (total_milliseconds % 1000) as u32
+    )
+}
+
+#[cfg(test)]
+mod lmst_tests {
+    use super::*;
+
+    #[test]
+    fn test_lmst_at_prime_meridian_equals_mtc() {
+        let clock = FixedClock::from_utc("2012-08-06T05:17:57Z").unwrap();
+        let result = lmst(msd_now_with_clock(&clock).unwrap(), 0.0).unwrap();
+        let mtc = mtc_now_with_clock(&clock).unwrap();
+
+        assert_eq!(result.hours, mtc.hours);
+        assert_eq!(result.minutes, mtc.minutes);
+        assert_eq!(result.seconds, mtc.seconds);
+    }
+
+    #[test]
+    fn test_lmst_at_gale_crater() {
+        // MTC 06:00:00 shifted by 137.4417° east => +9h 9m 46.008s
+        let result = lmst(49269.25, 137.4417).unwrap();
+
+        assert_eq!(result, Time::new(15, 9, 46, 8));
+    }
+
+    #[test]
+    fn test_lmst_wraps_around_midnight() {
+        let result = lmst(100.75, -180.0).unwrap();
+        assert_eq!(result, Time::new(6, 0, 0, 0));
+
+        let result = lmst(-0.25, 0.0).unwrap();
+        assert_eq!(result, Time::new(18, 0, 0, 0));
+    }
+
+    #[test]
+    fn test_lmst_now() {
+        let clock = FixedClock::from_utc("2012-08-06T05:17:57Z").unwrap();
+        let result = lmst_now_with_clock(&clock, 137.4417).unwrap();
+        let expected = lmst(msd_now_with_clock(&clock).unwrap(), 137.4417).unwrap();
+
+        assert_eq!(result, expected);
+        assert!(lmst_now(137.4417).is_ok());
+        assert_eq!(lmst_now(400.0).unwrap_err(), TimeError::InvalidArgumentError);
+    }
+
+    #[test]
+    fn test_lmst_invalid_longitude() {
+        assert_eq!(lmst(49269.25, 361.0).unwrap_err(), TimeError::InvalidArgumentError);
+        assert_eq!(lmst(49269.25, f64::NAN).unwrap_err(), TimeError::InvalidArgumentError);
+        assert_eq!(lmst(f64::INFINITY, 0.0).unwrap_err(), TimeError::InvalidArgumentError);
+    }
+
+    #[test]
+    fn test_local_sol() {
+        assert_eq!(local_sol(49269.75, 0.0).unwrap(), 49269);
+        assert_eq!(local_sol(49269.75, 137.4417).unwrap(), 49270);
+        assert_eq!(local_sol(49269.25, -137.4417).unwrap(), 49268);
+    }
+
+    #[test]
+    fn test_local_time_of_instant() {
+        let instant = MarsInstant::from_utc("2012-08-06T05:17:57Z").unwrap();
+        let msd = instant.msd();
+
+        assert_eq!(instant_to_lmst(instant, 137.4417).unwrap(), lmst(msd, 137.4417).unwrap());
+        assert_eq!(instant_to_ltst(instant, 137.4417).unwrap(), ltst(msd, 137.4417).unwrap());
+        assert_eq!(instant_to_lmst(instant.epoch(), 0.0).unwrap(), msd_to_mtc(msd).unwrap());
+
+        let result = instant_to_lmst(instant, 400.0);
+        assert_eq!(result.unwrap_err(), TimeError::InvalidArgumentError);
+    }
+
+    #[test]
+    fn test_local_time_same_meridian() {
+        // 270°E and 90°W are the same meridian
+        assert_eq!(local_sol(53625.5, 270.0).unwrap(), local_sol(53625.5, -90.0).unwrap());
+        assert_eq!(local_sol(53625.5, 270.0).unwrap(), 53625);
+        assert_eq!(lmst(53625.5, 270.0).unwrap(), lmst(53625.5, -90.0).unwrap());
+        assert_eq!(ltst(53625.5, 270.0).unwrap(), ltst(53625.5, -90.0).unwrap());
+        assert_eq!(local_sol(53625.9, 360.0).unwrap(), local_sol(53625.9, 0.0).unwrap());
+        assert_eq!(local_sol(53625.9, -180.0).unwrap(), local_sol(53625.9, 180.0).unwrap());
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+/// Mars mean anomaly in degrees (0 to 360) for a given Mars Sol Date (MSD).
+///
+/// Step B-1 of the Mars24 algorithm (Allison & McEwen, 2000).
+///
+/// # Examples
+///
+/// ```
+/// use martian::time::mean_anomaly;
+///
+/// let m = mean_anomaly(44795.9998).unwrap();
+/// println!("Mean anomaly: {:.5}°", m);
+/// ```
+///
+/// # Errors
+///
+/// - `TimeError::InvalidArgumentError`: If the MSD is not finite.
+pub fn mean_anomaly(msd: f64) -> Result<f64, TimeError> {
+    mean_anomaly_with_model(msd, &TimeModel::MARS24)
+}
+
+/// Same as `mean_anomaly` under the provided time model.
+///
+/// # Errors
+///
+/// - `TimeError::InvalidArgumentError`: If the MSD is not finite.
+pub fn mean_anomaly_with_model(msd: f64, model: &TimeModel) -> Result<f64, TimeError> {
+    let delta_j2000 = msd_to_j2000_offset(msd, model)?;
+    Ok(mean_anomaly_from_j2000_offset(delta_j2000).rem_euclid(360.0))
+}
+
+/// Angle of the Fictitious Mean Sun (right ascension) in degrees (0 to 360) for a given MSD.
+///
+/// Step B-2 of the Mars24 algorithm (Allison & McEwen, 2000).
+///
+/// # Errors
+///
+/// - `TimeError::InvalidArgumentError`: If the MSD is not finite.
+pub fn fictitious_mean_sun(msd: f64) -> Result<f64, TimeError> {
+    fictitious_mean_sun_with_model(msd, &TimeModel::MARS24)
+}
+
+/// Same as `fictitious_mean_sun` under the provided time model.
+///
+/// # Errors
+///
+/// - `TimeError::InvalidArgumentError`: If the MSD is not finite.
+pub fn fictitious_mean_sun_with_model(msd: f64, model: &TimeModel) -> Result<f64, TimeError> {
+    let delta_j2000 = msd_to_j2000_offset(msd, model)?;
+    Ok(fictitious_mean_sun_from_j2000_offset(delta_j2000).rem_euclid(360.0))
+}
+
+/// Sum of the planetary perturbations of Mars orbit in degrees for a given MSD.
+///
+/// Step B-3 of the Mars24 algorithm (Allison & McEwen, 2000).
+///
+/// # Errors
+///
+/// - `TimeError::InvalidArgumentError`: If the MSD is not finite.
+pub fn perturbers(msd: f64) -> Result<f64, TimeError> {
+    perturbers_with_model(msd, &TimeModel::MARS24)
+}
+
+/// Same as `perturbers` under the provided time model.
+///
+/// # Errors
+///
+/// - `TimeError::InvalidArgumentError`: If the MSD is not finite.
+pub fn perturbers_with_model(msd: f64, model: &TimeModel) -> Result<f64, TimeError> {
+    let delta_j2000 = msd_to_j2000_offset(msd, model)?;
+    Ok(perturbers_from_j2000_offset(delta_j2000))
+}
+
+/// Equation of center (true anomaly minus mean anomaly) in degrees for a given MSD.
+///
+/// Step B-4 of the Mars24 algorithm (Allison & McEwen, 2000).
+///
+/// # Errors
+///
+/// - `TimeError::InvalidArgumentError`: If the MSD is not finite.
+pub fn equation_of_center(msd: f64) -> Result<f64, TimeError> {
+    equation_of_center_with_model(msd, &TimeModel::MARS24)
+}
+
+/// Same as `equation_of_center` under the provided time model.
+///
+/// # Errors
+///
+/// - `TimeError::InvalidArgumentError`: If the MSD is not finite.
+pub fn equation_of_center_with_model(msd: f64, model: &TimeModel) -> Result<f64, TimeError> {
+    let delta_j2000 = msd_to_j2000_offset(msd, model)?;
+    Ok(equation_of_center_from_j2000_offset(delta_j2000))
+}
+
+/// Equation of time (true solar time minus mean solar time) in degrees for a given MSD.
+/// Divide by 15 to get the value in Mars hours.
+///
+/// Step C-1 of the Mars24 algorithm (Allison & McEwen, 2000).
+///
+/// # Examples
+///
+/// ```
+/// use martian::time::equation_of_time;
+///
+/// let eot = equation_of_time(44795.9998).unwrap();
+/// println!("Equation of time: {:.5}° ({:.5} h)", eot, eot / 15.0);
+/// ```
+///
+/// # Errors
+///
+/// - `TimeError::InvalidArgumentError`: If the MSD is not finite.
+pub fn equation_of_time(msd: f64) -> Result<f64, TimeError> {
+    equation_of_time_with_model(msd, &TimeModel::MARS24)
+}
+
+/// Same as `equation_of_time` under the provided time model.
+///
+/// # Errors
+///
+/// - `TimeError::InvalidArgumentError`: If the MSD is not finite.
+pub fn equation_of_time_with_model(msd: f64, model: &TimeModel) -> Result<f64, TimeError> {
+    let delta_j2000 = msd_to_j2000_offset(msd, model)?;
+    Ok(equation_of_time_from_j2000_offset(delta_j2000))
+}
+
+/// Convert Mars Sol Date (MSD) to Local True Solar Time (LTST) at a given Mars longitude.
+/// LTST follows the apparent position of the Sun, so it is LMST corrected by the equation of time.
+///
+/// # Arguments
+///
+/// * `msd` - Martian Sol Date for which LTST should be calculated.
+/// * `east_longitude` - Planetocentric east longitude of the site in degrees (-360 to 360).
+///
+/// # Examples
+///
+/// ```
+/// use martian::time::ltst;
+///
+/// // Curiosity Rover landing at Gale crater
+/// match ltst(49269.25, 137.4417) {
+///     Ok(ltst) => println!("Local True Solar Time: {}", ltst),
+///     Err(e) => eprintln!("Error calculating LTST: {}", e),
+/// }
+/// ```
+///
+/// # Errors
+///
+/// - `TimeError::InvalidArgumentError`: If the MSD is not finite or the longitude is out of range.
+/// - `TimeError::TimeCalculationError`: If the MSD is outside the range of a hifitime `Epoch`.
+pub fn ltst(msd: f64, east_longitude: f64) -> Result<Time, TimeError> {
+    instant_to_ltst(MarsInstant::from_msd(msd)?, east_longitude)
+}
+
+/// Get Local True Solar Time (LTST) of a `MarsInstant`, or anything convertible into one such as
+/// a hifitime `Epoch` or `SystemTime`, at a given Mars longitude.
+///
+/// # Examples
+///
+/// ```
+/// use martian::time::{ instant_to_ltst, MarsInstant };
+///
+/// let instant = MarsInstant::from_utc("2012-08-06T05:17:57Z").unwrap();
+/// println!("Local True Solar Time: {}", instant_to_ltst(instant, 137.4417).unwrap());
+/// ```
+///
+/// # Errors
+///
+/// - `TimeError::InvalidArgumentError`: If the longitude is out of range.
+pub fn instant_to_ltst<I>(instant: I, east_longitude: f64) -> Result<Time, TimeError>
+    where I: Into<MarsInstant>
+{
+    ltst_with_model(instant.into().msd(), east_longitude, &TimeModel::MARS24)
+}
+
+/// Same as `ltst` under the provided time model.
+///
+/// # Errors
+///
+/// - `TimeError::InvalidArgumentError`: If the MSD is not finite or the longitude is out of range.
+pub fn ltst_with_model(
+    msd: f64,
+    east_longitude: f64,
+    model: &TimeModel
+) -> Result<Time, TimeError> {
+    let local_msd = local_msd(msd, east_longitude)?;
+    let eot = equation_of_time_with_model(msd, model)?;
+
+    // LTST = LMST + EOT * (24h / 360°)
+    Ok(sol_fraction_to_time(local_msd + eot / 360.0))
+}
+
+/// Heliocentric distance of Mars in astronomical units (AU) for a given MSD.
+///
+/// Step D-3 of the Mars24 algorithm (Allison & McEwen, 2000).
+///
+/// # Examples
+///
+/// ```
+/// use martian::time::heliocentric_distance;
+///
+/// let distance = heliocentric_distance(53626.0011).unwrap();
+/// println!("Mars-Sun distance: {:.5} AU", distance);
+/// ```
+///
+/// # Errors
+///
+/// - `TimeError::InvalidArgumentError`: If the MSD is not finite.
+pub fn heliocentric_distance(msd: f64) -> Result<f64, TimeError> {
+    heliocentric_distance_with_model(msd, &TimeModel::MARS24)
+}
+
+/// Same as `heliocentric_distance` under the provided time model.
+///
+/// # Errors
+///
+/// - `TimeError::InvalidArgumentError`: If the MSD is not finite.
+pub fn heliocentric_distance_with_model(msd: f64, model: &TimeModel) -> Result<f64, TimeError> {
+    let delta_j2000 = msd_to_j2000_offset(msd, model)?;
+    let m = mean_anomaly_from_j2000_offset(delta_j2000).to_radians();
+
+    Ok(
+        MARS_SEMI_MAJOR_AXIS_AU *
+            (1.00436 -
+                0.09309 * m.cos() -
+                0.004336 * (2.0 * m).cos() -
+                0.00031 * (3.0 * m).cos() -
+                0.00003 * (4.0 * m).cos())
+    )
+}
+
+// Number of Julian days (TT) since the J2000.0 epoch for a given MSD
+fn msd_to_j2000_offset(msd: f64, model: &TimeModel) -> Result<f64, TimeError> {
+    if !msd.is_finite() {
+        return Err(TimeError::InvalidArgumentError);
+    }
+
+    let jd = model.msd_to_jd(msd);
+    let jde_tt = match model.julian_date_scale() {
+        JulianDateScale::Tt => jd,
+        scale => Epoch::from_jde_in_time_scale(jd, scale.into()).to_jde_tt_days(),
+    };
+
+    Ok(jde_tt - JD_J2000)
+}
+
+fn mean_anomaly_from_j2000_offset(delta_j2000: f64) -> f64 {
+    19.3871 + 0.52402073 * delta_j2000
+}
+
+fn fictitious_mean_sun_from_j2000_offset(delta_j2000: f64) -> f64 {
+    270.3871 + 0.524038496 * delta_j2000
+}
+
+fn perturbers_from_j2000_offset(delta_j2000: f64) -> f64 {
+    (0..7)
+        .map(|i| {
+            let angle = (0.985626 * delta_j2000) / PERTURBER_PERIODS[i] + PERTURBER_PHASES[i];
+            PERTURBER_AMPLITUDES[i] * angle.to_radians().cos()
+        })
+        .sum()
+}
+
+fn equation_of_center_from_j2000_offset(delta_j2000: f64) -> f64 {
+    let m = mean_anomaly_from_j2000_offset(delta_j2000).to_radians();
+
+    (10.691 + 3.0e-7 * delta_j2000) * m.sin() +
+        0.623 * (2.0 * m).sin() +
+        0.05 * (3.0 * m).sin() +
+        0.005 * (4.0 * m).sin() +
+        0.0005 * (5.0 * m).sin() +
+        perturbers_from_j2000_offset(delta_j2000)
+}
+
+// Areocentric solar longitude (Ls) in degrees, step B-5 of the Mars24 algorithm
+fn solar_longitude_from_j2000_offset(delta_j2000: f64) -> f64 {
+    let ls =
+        fictitious_mean_sun_from_j2000_offset(delta_j2000) +
+        equation_of_center_from_j2000_offset(delta_j2000);
+    ls.rem_euclid(360.0)
+}
+
+fn equation_of_time_from_j2000_offset(delta_j2000: f64) -> f64 {
+    let ls = solar_longitude_from_j2000_offset(delta_j2000).to_radians();
+
+    2.861 * (2.0 * ls).sin() - 0.071 * (4.0 * ls).sin() + 0.002 * (6.0 * ls).sin() -
+        equation_of_center_from_j2000_offset(delta_j2000)
+}
+
+#[cfg(test)]
+mod mars24_tests {
+    use super::*;
+
+    // Worked example from Allison & McEwen (2000) for 2000-01-06T00:00:00 UTC
+    // Values taken from https://www.giss.nasa.gov/tools/mars24/help/algorithm.html
+    fn example_msd() -> f64 {
+        utc_to_msd("2000-01-06T00:00:00.000").unwrap()
+    }
+
+    #[test]
+    fn test_j2000_offset() {
+        let result = msd_to_j2000_offset(example_msd(), &TimeModel::MARS24).unwrap();
+        assert!((result - 4.5007428).abs() < 0.00001, "Δt J2000: {}", result);
+    }
+
+    #[test]
+    fn test_mean_anomaly() {
+        let result = mean_anomaly(example_msd()).unwrap();
+        assert!((result - 21.74558).abs() < 0.00001, "M: {}", result);
+    }
+
+    #[test]
+    fn test_fictitious_mean_sun() {
+        let result = fictitious_mean_sun(example_msd()).unwrap();
+        assert!((result - 272.74566).abs() < 0.00001, "α FMS: {}", result);
+    }
+
+    #[test]
+    fn test_perturbers() {
+        let result = perturbers(example_msd()).unwrap();
+        assert!((result - 0.00142).abs() < 0.00001, "PBS: {}", result);
+    }
+
+    #[test]
+    fn test_equation_of_center() {
+        let result = equation_of_center(example_msd()).unwrap();
+        assert!((result - 4.44193).abs() < 0.00001, "ν - M: {}", result);
+    }
+
+    #[test]
+    fn test_solar_longitude() {
+        let delta_j2000 = msd_to_j2000_offset(example_msd(), &TimeModel::MARS24).unwrap();
+        let result = solar_longitude_from_j2000_offset(delta_j2000);
+        assert!((result - 277.18758).abs() < 0.00001, "Ls: {}", result);
+    }
+
+    #[test]
+    fn test_equation_of_time() {
+        let result = equation_of_time(example_msd()).unwrap();
+        assert!((result + 5.18774).abs() < 0.00001, "EOT: {}", result);
+    }
+
+    #[test]
+    fn test_ltst() {
+        // LTST at 0° longitude: 23.64840 h
+        let result = ltst(example_msd(), 0.0).unwrap();
+        assert_eq!((result.hours, result.minutes, result.seconds), (23, 38, 54));
+    }
+
+    #[test]
+    fn test_heliocentric_distance() {
+        let result = heliocentric_distance(example_msd()).unwrap();
+        assert!((result - 1.39359).abs() < 0.00001, "r: {}", result);
+
+        // Perihelion and aphelion distances
+        let distances: Vec<f64> = (0..669)
+            .map(|sol| heliocentric_distance(53000.0 + (sol as f64)).unwrap())
+            .collect();
+        let min = distances.iter().cloned().fold(f64::MAX, f64::min);
+        let max = distances.iter().cloned().fold(f64::MIN, f64::max);
+
+        assert!((min - 1.3814).abs() < 0.001, "Perihelion: {}", min);
+        assert!((max - 1.6660).abs() < 0.001, "Aphelion: {}", max);
+    }
+
+    #[test]
+    fn test_ltst_invalid_argument() {
+        assert_eq!(ltst(f64::NAN, 0.0).unwrap_err(), TimeError::InvalidArgumentError);
+        assert_eq!(ltst(44795.9998, 400.0).unwrap_err(), TimeError::InvalidArgumentError);
+        assert_eq!(equation_of_time(f64::NAN).unwrap_err(), TimeError::InvalidArgumentError);
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+/// Areocentric solar longitude (Ls) in degrees (0 to 360) for a given Mars Sol Date (MSD).
+/// Ls 0° is the northern spring equinox, 90° northern summer solstice, 180° northern autumn
+/// equinox and 270° northern winter solstice.
+///
+/// # Examples
+///
+/// ```
+/// use martian::time::msd_to_ls;
+///
+/// match msd_to_ls(53626.0011) {
+///     Ok(ls) => println!("Solar longitude: {:.3}°", ls),
+///     Err(e) => eprintln!("Error calculating Ls: {}", e),
+/// }
+/// ```
+///
+/// # Errors
+///
+/// - `TimeError::InvalidArgumentError`: If the MSD is not finite.
+/// - `TimeError::TimeCalculationError`: If the MSD is outside the range of a hifitime `Epoch`.
+pub fn msd_to_ls(msd: f64) -> Result<f64, TimeError> {
+    instant_to_ls(MarsInstant::from_msd(msd)?)
+}
+
+/// Areocentric solar longitude (Ls) in degrees (0 to 360) of a `MarsInstant`, or anything
+/// convertible into one such as a hifitime `Epoch` or `SystemTime`.
+///
+/// # Examples
+///
+/// ```
+/// use martian::time::{ instant_to_ls, MarsInstant };
+///
+/// let instant = MarsInstant::from_utc("2024-11-07T17:58:40Z").unwrap();
+/// println!("Solar longitude: {:.3}°", instant_to_ls(instant).unwrap());
+/// ```
+///
+/// # Errors
+///
+/// - `TimeError::InvalidArgumentError`: If the instant can't be expressed as MSD.
+pub fn instant_to_ls<I>(instant: I) -> Result<f64, TimeError>
+    where I: Into<MarsInstant>
+{
+    msd_to_ls_with_model(instant.into().msd(), &TimeModel::MARS24)
+}
+
+/// Same as `msd_to_ls` under the provided time model.
+///
+/// # Errors
+///
+/// - `TimeError::InvalidArgumentError`: If the MSD is not finite.
+pub fn msd_to_ls_with_model(msd: f64, model: &TimeModel) -> Result<f64, TimeError> {
+    let delta_j2000 = msd_to_j2000_offset(msd, model)?;
+    Ok(solar_longitude_from_j2000_offset(delta_j2000))
+}
+
+/// Convert UTC datetime to the areocentric solar longitude (Ls) in degrees. Requires an ISO8601
+/// formatted datetime string as input.
+///
+/// # Examples
+///
+/// ```
+/// use martian::time::utc_to_ls;
+///
+/// let ls = utc_to_ls("2024-11-07T17:58:40.000").unwrap();
+/// println!("Solar longitude: {:.3}°", ls);
+/// ```
+///
+/// # Errors
+///
+/// May propagate Errors from `utc_to_msd` if function fails.
+///
+/// - `TimeError::ISO8601FormatError`: If the provided date does not match the ISO8601 format.
+/// - `TimeError::InvalidDateTimeField`: If a datetime field is out of range (eg. 30 February).
+/// - `TimeError::TimeCalculationError`: If the calculated MSD is invalid or out of expected bounds.
+pub fn utc_to_ls(datetime: &str) -> Result<f64, TimeError> {
+    utc_to_ls_with_model(datetime, &TimeModel::MARS24)
+}
+
+/// Same as `utc_to_ls` under the provided time model.
+///
+/// # Errors
+///
+/// May propagate Errors from `utc_to_msd` if function fails.
+///
+/// - `TimeError::ISO8601FormatError`: If the provided date does not match the ISO8601 format.
+/// - `TimeError::InvalidDateTimeField`: If a datetime field is out of range (eg. 30 February).
+/// - `TimeError::TimeCalculationError`: If the calculated MSD is invalid or out of expected bounds.
+pub fn utc_to_ls_with_model(datetime: &str, model: &TimeModel) -> Result<f64, TimeError> {
+    let msd = utc_to_msd_with_model(datetime, model)?;
+    msd_to_ls_with_model(msd, model)
+}
+
+/// Mars Year (MY) number for a given Mars Sol Date (MSD), following the Clancy et al. (2000)
+/// convention where Mars Year 1 began at Ls 0° on 1955-04-11.
+///
+/// # Examples
+///
+/// ```
+/// use martian::time::msd_to_mars_year;
+///
+/// let mars_year = msd_to_mars_year(53626.0011).unwrap();
+/// assert_eq!(mars_year, 37);
+/// ```
+///
+/// # Errors
+///
+/// - `TimeError::InvalidArgumentError`: If the MSD is not finite.
+/// - `TimeError::TimeCalculationError`: If the start of the Mars Year could not be determined.
+pub fn msd_to_mars_year(msd: f64) -> Result<i32, TimeError> {
+    msd_to_mars_year_with_model(msd, &TimeModel::MARS24)
+}
+
+/// Same as `msd_to_mars_year` under the provided time model.
+///
+/// # Errors
+///
+/// - `TimeError::InvalidArgumentError`: If the MSD is not finite.
+/// - `TimeError::TimeCalculationError`: If the start of the Mars Year could not be determined.
+pub fn msd_to_mars_year_with_model(msd: f64, model: &TimeModel) -> Result<i32, TimeError> {
+    if !msd.is_finite() {
+        return Err(TimeError::InvalidArgumentError);
+    }
+
+    let estimate = ((msd - MARS_YEAR_ONE_START_MSD) / MARS_TROPICAL_YEAR_SOLS).floor() as i32 + 1;
+
+    // Mean year length is only an estimate, correct it with the exact equinox crossings
+    if msd < mars_year_ls_to_msd_with_model(estimate, 0.0, model)? {
+        Ok(estimate - 1)
+    } else if msd >= mars_year_ls_to_msd_with_model(estimate + 1, 0.0, model)? {
+        Ok(estimate + 1)
+    } else {
+        Ok(estimate)
+    }
+}
+
+/// Convert UTC datetime to the Mars Year (MY) number. Requires an ISO8601 formatted datetime
+/// string as input.
+///
+/// # Examples
+///
+/// ```
+/// use martian::time::utc_to_mars_year;
+///
+/// let mars_year = utc_to_mars_year("2024-11-07T17:58:40.000").unwrap();
+/// assert_eq!(mars_year, 37);
+/// ```
+///
+/// # Errors
+///
+/// May propagate Errors from `utc_to_msd` and `msd_to_mars_year` if functions fail.
+///
+/// - `TimeError::ISO8601FormatError`: If the provided date does not match the ISO8601 format.
+/// - `TimeError::InvalidDateTimeField`: If a datetime field is out of range (eg. 30 February).
+/// - `TimeError::TimeCalculationError`: If the calculated MSD is invalid or out of expected bounds.
+pub fn utc_to_mars_year(datetime: &str) -> Result<i32, TimeError> {
+    utc_to_mars_year_with_model(datetime, &TimeModel::MARS24)
+}
+
+/// Same as `utc_to_mars_year` under the provided time model.
+///
+/// # Errors
+///
+/// May propagate Errors from `utc_to_msd` and `msd_to_mars_year` if functions fail.
+///
+/// - `TimeError::ISO8601FormatError`: If the provided date does not match the ISO8601 format.
+/// - `TimeError::InvalidDateTimeField`: If a datetime field is out of range (eg. 30 February).
+/// - `TimeError::TimeCalculationError`: If the calculated MSD is invalid or out of expected bounds.
+pub fn utc_to_mars_year_with_model(datetime: &str, model: &TimeModel) -> Result<i32, TimeError> {
+    let msd = utc_to_msd_with_model(datetime, model)?;
+    msd_to_mars_year_with_model(msd, model)
+}
+
+/// Mars Sol Date (MSD) at which a given Mars Year reaches a given solar longitude (Ls).
+///
+/// # Arguments
+///
+/// * `mars_year` - Mars Year number (Clancy et al. convention).
+/// * `ls` - Areocentric solar longitude in degrees (0 to 360, exclusive).
+///
+/// # Examples
+///
+/// ```
+/// use martian::time::mars_year_ls_to_msd;
+///
+/// // Northern summer solstice of Mars Year 37
+/// let msd = mars_year_ls_to_msd(37, 90.0).unwrap();
+/// println!("Mars Sol Date: {:.5}", msd);
+/// ```
+///
+/// # Errors
+///
+/// - `TimeError::InvalidArgumentError`: If Ls is outside of 0 to 360 range.
+/// - `TimeError::TimeCalculationError`: If the calculation did not converge.
+pub fn mars_year_ls_to_msd(mars_year: i32, ls: f64) -> Result<f64, TimeError> {
+    mars_year_ls_to_msd_with_model(mars_year, ls, &TimeModel::MARS24)
+}
+
+/// Same as `mars_year_ls_to_msd` under the provided time model.
+///
+/// # Errors
+///
+/// - `TimeError::InvalidArgumentError`: If Ls is outside of 0 to 360 range.
+/// - `TimeError::TimeCalculationError`: If the calculation did not converge.
+pub fn mars_year_ls_to_msd_with_model(
+    mars_year: i32,
+    ls: f64,
+    model: &TimeModel
+) -> Result<f64, TimeError> {
+    if !(0.0..360.0).contains(&ls) {
+        return Err(TimeError::InvalidArgumentError);
+    }
+
+    // Initial guess assumes constant angular speed along the orbit
+    let mut msd =
+        MARS_YEAR_ONE_START_MSD + ((mars_year as f64) - 1.0 + ls / 360.0) * MARS_TROPICAL_YEAR_SOLS;
+
+    // Newton iterations, Ls changes by 0.44° to 0.64° per sol so the guess is never more than
+    // a few dozen sols off
+    for _ in 0..20 {
+        let ls_difference = msd_to_ls_with_model(msd, model)? - ls;
+        let difference = (ls_difference + 180.0).rem_euclid(360.0) - 180.0;
+        if difference.abs() < 1.0e-9 {
+            return Ok(msd);
+        }
+
+        let rate =
+            msd_to_ls_with_model(msd + 0.5, model)? - msd_to_ls_with_model(msd - 0.5, model)?;
+        let rate = (rate + 180.0).rem_euclid(360.0) - 180.0;
+        msd -= difference / rate;
+    }
+
+    Err(TimeError::TimeCalculationError)
+}
+
+/// UTC datetime at which a given Mars Year reaches a given solar longitude (Ls). Returns an
+/// ISO8601 formatted datetime string.
+///
+/// # Examples
+///
+/// ```
+/// use martian::time::mars_year_ls_to_utc;
+///
+/// // Northern spring equinox starting Mars Year 38
+/// match mars_year_ls_to_utc(38, 0.0) {
+///     Ok(utc) => println!("UTC: {}", utc),
+///     Err(e) => eprintln!("Error calculating UTC: {}", e),
+/// }
+/// ```
+///
+/// # Errors
+///
+/// - `TimeError::InvalidArgumentError`: If Ls is outside of 0 to 360 range.
+/// - `TimeError::TimeCalculationError`: If the calculation did not converge.
+pub fn mars_year_ls_to_utc(mars_year: i32, ls: f64) -> Result<String, TimeError> {
+    mars_year_ls_to_utc_with_model(mars_year, ls, &TimeModel::MARS24)
+}
+
+/// Same as `mars_year_ls_to_utc` under the provided time model.
+///
+/// # Errors
+///
+/// - `TimeError::InvalidArgumentError`: If Ls is outside of 0 to 360 range.
+/// - `TimeError::TimeCalculationError`: If the calculation did not converge.
+pub fn mars_year_ls_to_utc_with_model(
+    mars_year: i32,
+    ls: f64,
+    model: &TimeModel
+) -> Result<String, TimeError> {
+    let msd = mars_year_ls_to_msd_with_model(mars_year, ls, model)?;
+    msd_to_utc_with_model(msd, model)
+}
+
+#[cfg(test)]
+mod mars_year_tests {
+    use super::*;
+
+    #[test]
+    fn test_msd_to_ls() {
+        let msd = utc_to_msd("2000-01-06T00:00:00.000").unwrap();
+        let result = msd_to_ls(msd).unwrap();
+
+        assert!((result - 277.18758).abs() < 0.00001, "Ls: {}", result);
+    }
+
+    #[test]
+    fn test_instant_to_ls() {
+        let instant = MarsInstant::from_utc("2000-01-06T00:00:00Z").unwrap();
+        let result = instant_to_ls(instant).unwrap();
+
+        assert_eq!(result, msd_to_ls(instant.msd()).unwrap());
+        assert_eq!(instant_to_ls(instant.epoch()).unwrap(), result);
+        assert_eq!(msd_to_ls(f64::NAN).unwrap_err(), TimeError::InvalidArgumentError);
+    }
+
+    #[test]
+    fn test_utc_to_ls() {
+        // Curiosity landed at Ls 150.7°
+        let result = utc_to_ls("2012-08-06T05:17:57.000").unwrap();
+
+        assert!((result - 150.7).abs() < 0.1, "Ls: {}", result);
+    }
+
+    #[test]
+    fn test_mars_year() {
+        // Mars Year 36 started on 2021-02-07, Mars Year 37 on 2022-12-26 and 38 on 2024-11-12
+        assert_eq!(utc_to_mars_year("2021-02-06T12:00:00.000").unwrap(), 35);
+        assert_eq!(utc_to_mars_year("2021-02-08T12:00:00.000").unwrap(), 36);
+        assert_eq!(utc_to_mars_year("2022-12-25T00:00:00.000").unwrap(), 36);
+        assert_eq!(utc_to_mars_year("2022-12-27T00:00:00.000").unwrap(), 37);
+        assert_eq!(utc_to_mars_year("2024-11-11T00:00:00.000").unwrap(), 37);
+        assert_eq!(utc_to_mars_year("2024-11-13T00:00:00.000").unwrap(), 38);
+
+        // Mars Year 1 started on 1955-04-11
+        assert_eq!(utc_to_mars_year("1955-04-12T00:00:00.000").unwrap(), 1);
+        assert_eq!(utc_to_mars_year("1955-04-10T00:00:00.000").unwrap(), 0);
+    }
+
+    #[test]
+    fn test_mars_year_ls_to_msd() {
+        for (mars_year, ls) in [(1, 0.0), (37, 90.0), (37, 251.3), (38, 359.9), (-10, 180.0)] {
+            let msd = mars_year_ls_to_msd(mars_year, ls).unwrap();
+
+            let result_ls = msd_to_ls(msd).unwrap();
+            let difference = (result_ls - ls + 180.0).rem_euclid(360.0) - 180.0;
+            assert!(difference.abs() < 1.0e-6, "Ls: {} != {}", result_ls, ls);
+
+            // Equinox belongs to the new year, so check just after the crossing
+            let result_year = msd_to_mars_year(msd + 1.0e-6).unwrap();
+            assert_eq!(result_year, mars_year);
+        }
+    }
+
+    #[test]
+    fn test_mars_year_ls_to_utc() {
+        let result = mars_year_ls_to_utc(38, 0.0).unwrap();
+
+        assert!(result.starts_with("2024-11-12"), "UTC: {}", result);
+    }
+
+    #[test]
+    fn test_mars_year_ls_invalid_ls() {
+        assert_eq!(mars_year_ls_to_msd(37, 360.0).unwrap_err(), TimeError::InvalidArgumentError);
+        assert_eq!(mars_year_ls_to_msd(37, -1.0).unwrap_err(), TimeError::InvalidArgumentError);
+        assert_eq!(msd_to_mars_year(f64::NAN).unwrap_err(), TimeError::InvalidArgumentError);
+    }
 }
@@ -1,11 +1,15 @@
 //! Module responsible for handling time related operations.
 
+mod clock;
 mod constants;
+mod earth_time;
 mod functions;
 mod structs;
 mod errors;
 
+pub use clock::*;
 pub use functions::*;
 pub use constants::*;
+pub use earth_time::*;
 pub use structs::*;
 pub use errors::*;
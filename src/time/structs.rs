@@ -1,8 +1,39 @@
 use std::fmt;
+use std::time::SystemTime;
+use std::ops::{ Add, AddAssign, Mul, Neg, Sub, SubAssign };
+use hifitime::{ Duration, Epoch, TimeScale, Unit };
+use crate::time::clock::{ Clock, SystemClock };
+use crate::time::constants::{
+    ALLISON_1997_JD_ON_SOL_ZERO,
+    ALLISON_1997_SOL_IN_EARTH_DAYS,
+    JD_ON_MJD_ZERO,
+    JD_ON_SOL_ZERO,
+    MARS_TIME_ZONE_WIDTH,
+    NANOSECONDS_PER_DAY,
+    SOL_IN_EARTH_DAYS,
+};
+use crate::time::errors::TimeError;
+use crate::time::functions::{
+    epoch_from_system_time,
+    epoch_to_msd_with_model,
+    instant_to_lmst,
+    instant_to_ls,
+    instant_to_ltst,
+    instant_to_utc,
+    lmst,
+    ltst_with_model,
+    milliseconds_to_time,
+    msd_to_ls_with_model,
+    msd_to_mars_year,
+    msd_to_mars_year_with_model,
+    mtc_to_msd,
+    parse_utc_epoch,
+    sol_fraction_to_time,
+};
 
 // ------------------------------------------------------------------------------------------------
 /// Represents a time value with hours, minutes, seconds, and milliseconds.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Time {
     pub hours: u32,
     pub minutes: u32,
@@ -34,3 +65,1601 @@ impl fmt::Display for Time {
         )
     }
 }
+
+// ------------------------------------------------------------------------------------------------
+/// Represents a single instant in time, usable for all Mars time representations. Wraps a
+/// hifitime `Epoch`, so it can't be confused with a Julian date or Unix time the way a bare
+/// `f64` MSD can.
+///
+/// # Examples
+///
+/// ```
+/// use martian::time::MarsInstant;
+///
+/// let instant = MarsInstant::from_utc("2024-11-07T17:58:40.000").unwrap();
+/// println!("{}, MTC {}, Ls {:.2}°", instant, instant.mtc(), instant.ls().unwrap());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MarsInstant {
+    epoch: Epoch,
+}
+
+impl MarsInstant {
+    /// Constructs a new `MarsInstant` instance from a hifitime `Epoch` in any time scale.
+    pub fn from_epoch(epoch: Epoch) -> Self {
+        Self { epoch }
+    }
+
+    /// Constructs a new `MarsInstant` instance from an ISO8601 formatted UTC datetime string.
+    ///
+    /// # Errors
+    ///
+    /// - `TimeError::ISO8601FormatError`: If the provided date does not match the ISO8601 format.
+    /// - `TimeError::InvalidDateTimeField`: If a datetime field is out of range.
+    pub fn from_utc(datetime: &str) -> Result<Self, TimeError> {
+        Ok(Self::from_epoch(parse_utc_epoch(datetime)?))
+    }
+
+    /// Constructs a new `MarsInstant` instance from a Julian Date in Terrestrial Time (TT).
+    ///
+    /// # Errors
+    ///
+    /// - `TimeError::InvalidArgumentError`: If the Julian Date is not finite.
+    pub fn from_jde_tt(jde_tt: f64) -> Result<Self, TimeError> {
+        if !jde_tt.is_finite() {
+            return Err(TimeError::InvalidArgumentError);
+        }
+
+        Ok(Self::from_epoch(Epoch::from_jde_in_time_scale(jde_tt, TimeScale::TT)))
+    }
+
+    /// Constructs a new `MarsInstant` instance from a Mars Sol Date (MSD).
+    ///
+    /// # Errors
+    ///
+    /// - `TimeError::InvalidArgumentError`: If the MSD is not finite.
+    /// - `TimeError::TimeCalculationError`: If the MSD is outside the range of a hifitime `Epoch`.
+    pub fn from_msd(msd: f64) -> Result<Self, TimeError> {
+        Self::from_msd_with_model(msd, &TimeModel::MARS24)
+    }
+
+    /// Constructs a new `MarsInstant` instance from a Mars Sol Date (MSD) under the provided time
+    /// model.
+    ///
+    /// # Errors
+    ///
+    /// - `TimeError::InvalidArgumentError`: If the MSD is not finite.
+    /// - `TimeError::TimeCalculationError`: If the MSD is outside the range of a hifitime `Epoch`.
+    pub fn from_msd_with_model(msd: f64, model: &TimeModel) -> Result<Self, TimeError> {
+        let sol_date = MarsSolDate::from_msd_with_model(msd, model)?;
+        Ok(Self::from_epoch(sol_date.to_epoch_with_model(model)))
+    }
+
+    /// Constructs a new `MarsInstant` instance for the current moment.
+    ///
+    /// # Errors
+    ///
+    /// - `TimeError::UtcTimeUnavailable`: If the current UTC time cannot be retrieved.
+    pub fn now() -> Result<Self, TimeError> {
+        Self::now_with_clock(&SystemClock)
+    }
+
+    /// Constructs a new `MarsInstant` instance for the current moment of the provided clock.
+    ///
+    /// # Errors
+    ///
+    /// - `TimeError::UtcTimeUnavailable`: If the clock can't provide the current time.
+    pub fn now_with_clock<C>(clock: &C) -> Result<Self, TimeError>
+        where C: Clock + ?Sized
+    {
+        Ok(Self::from_epoch(clock.now()?))
+    }
+
+    /// Returns the underlying hifitime `Epoch`.
+    pub fn epoch(&self) -> Epoch {
+        self.epoch
+    }
+
+    /// Returns the Mars Sol Date (MSD).
+    pub fn msd(&self) -> f64 {
+        self.msd_with_model(&TimeModel::MARS24)
+    }
+
+    /// Returns the Mars Sol Date (MSD) under the provided time model.
+    pub fn msd_with_model(&self, model: &TimeModel) -> f64 {
+        // Whole sols and nanoseconds of an Epoch always give a finite MSD
+        MarsSolDate::from_epoch_with_model(self.epoch, model).to_msd_with_model(model)
+    }
+
+    /// Returns the Mars Sol Date (MSD) split into a whole sol and nanoseconds, without rounding.
+    pub fn sol_date(&self) -> MarsSolDate {
+        MarsSolDate::from_epoch(self.epoch)
+    }
+
+    /// Returns the Julian Date in Terrestrial Time (TT).
+    pub fn jde_tt(&self) -> f64 {
+        self.epoch.to_jde_tt_days()
+    }
+
+    /// Returns the ISO8601 formatted UTC datetime string.
+    pub fn utc(&self) -> String {
+        instant_to_utc(*self)
+    }
+
+    /// Returns the Coordinated Mars Time (MTC) on a 24-hour Martian clock.
+    pub fn mtc(&self) -> Time {
+        sol_fraction_to_time(self.msd())
+    }
+
+    /// Returns the Local Mean Solar Time (LMST) at a given planetocentric east longitude.
+    ///
+    /// # Errors
+    ///
+    /// - `TimeError::InvalidArgumentError`: If the longitude is out of range.
+    pub fn lmst(&self, east_longitude: f64) -> Result<Time, TimeError> {
+        instant_to_lmst(*self, east_longitude)
+    }
+
+    /// Returns the Local True Solar Time (LTST) at a given planetocentric east longitude.
+    ///
+    /// # Errors
+    ///
+    /// - `TimeError::InvalidArgumentError`: If the longitude is out of range.
+    pub fn ltst(&self, east_longitude: f64) -> Result<Time, TimeError> {
+        instant_to_ltst(*self, east_longitude)
+    }
+
+    /// Returns the areocentric solar longitude (Ls) in degrees.
+    ///
+    /// # Errors
+    ///
+    /// - `TimeError::InvalidArgumentError`: If the instant can't be expressed as MSD.
+    pub fn ls(&self) -> Result<f64, TimeError> {
+        instant_to_ls(*self)
+    }
+
+    /// Returns the Mars Year (MY) number.
+    ///
+    /// # Errors
+    ///
+    /// - `TimeError::InvalidArgumentError`: If the instant can't be expressed as MSD.
+    /// - `TimeError::TimeCalculationError`: If the start of the Mars Year could not be determined.
+    pub fn mars_year(&self) -> Result<i32, TimeError> {
+        msd_to_mars_year(self.msd())
+    }
+}
+
+impl Add<MarsDuration> for MarsInstant {
+    type Output = MarsInstant;
+
+    fn add(self, duration: MarsDuration) -> MarsInstant {
+        MarsInstant::from_epoch(self.epoch + duration.to_duration())
+    }
+}
+
+impl AddAssign<MarsDuration> for MarsInstant {
+    fn add_assign(&mut self, duration: MarsDuration) {
+        *self = *self + duration;
+    }
+}
+
+impl Sub<MarsDuration> for MarsInstant {
+    type Output = MarsInstant;
+
+    fn sub(self, duration: MarsDuration) -> MarsInstant {
+        MarsInstant::from_epoch(self.epoch - duration.to_duration())
+    }
+}
+
+impl SubAssign<MarsDuration> for MarsInstant {
+    fn sub_assign(&mut self, duration: MarsDuration) {
+        *self = *self - duration;
+    }
+}
+
+impl Sub<MarsInstant> for MarsInstant {
+    type Output = MarsDuration;
+
+    fn sub(self, other: MarsInstant) -> MarsDuration {
+        MarsDuration::from_duration(self.epoch - other.epoch)
+    }
+}
+
+impl From<Epoch> for MarsInstant {
+    fn from(epoch: Epoch) -> Self {
+        Self::from_epoch(epoch)
+    }
+}
+
+impl From<SystemTime> for MarsInstant {
+    fn from(time: SystemTime) -> Self {
+        Self::from_epoch(epoch_from_system_time(time))
+    }
+}
+
+impl fmt::Display for MarsInstant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "MSD {:.5}", self.msd())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+/// Snapshot of the current moment on Mars. The clock is read once and every representation is
+/// derived from that single instant, so the sol, MTC and Darian date never disagree around
+/// midnight the way separate `msd_now`, `mtc_now` and `darian_now` calls can.
+///
+/// # Examples
+///
+/// ```
+/// use martian::time::MarsNow;
+///
+/// let now = MarsNow::now().unwrap();
+/// let (mars_year, ls) = (now.mars_year().unwrap(), now.ls().unwrap());
+/// println!("MSD {:.5}, MTC {}, MY {} Ls {:.2}°", now.msd, now.mtc, mars_year, ls);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MarsNow {
+    /// Instant the snapshot was taken at.
+    pub instant: MarsInstant,
+    /// Mars Sol Date (MSD).
+    pub msd: f64,
+    /// Coordinated Mars Time (MTC) on a 24-hour Martian clock.
+    pub mtc: Time,
+    /// Time model the MSD was computed under.
+    pub model: TimeModel,
+}
+
+impl MarsNow {
+    /// Takes a snapshot of the current moment.
+    ///
+    /// # Errors
+    ///
+    /// - `TimeError::UtcTimeUnavailable`: If the current UTC time cannot be retrieved.
+    /// - `TimeError::TimeCalculationError`: If the calculated MSD is out of expected bounds.
+    pub fn now() -> Result<Self, TimeError> {
+        Self::now_with_clock(&SystemClock)
+    }
+
+    /// Takes a snapshot of the current moment of the provided clock.
+    ///
+    /// # Errors
+    ///
+    /// - `TimeError::UtcTimeUnavailable`: If the clock can't provide the current time.
+    /// - `TimeError::TimeCalculationError`: If the calculated MSD is out of expected bounds.
+    pub fn now_with_clock<C>(clock: &C) -> Result<Self, TimeError>
+        where C: Clock + ?Sized
+    {
+        Self::now_with_clock_and_model(clock, &TimeModel::MARS24)
+    }
+
+    /// Same as `MarsNow::now` under the provided time model.
+    ///
+    /// # Errors
+    ///
+    /// - `TimeError::UtcTimeUnavailable`: If the current UTC time cannot be retrieved.
+    /// - `TimeError::TimeCalculationError`: If the calculated MSD is out of expected bounds.
+    pub fn now_with_model(model: &TimeModel) -> Result<Self, TimeError> {
+        Self::now_with_clock_and_model(&SystemClock, model)
+    }
+
+    /// Same as `MarsNow::now_with_clock` under the provided time model.
+    ///
+    /// # Errors
+    ///
+    /// - `TimeError::UtcTimeUnavailable`: If the clock can't provide the current time.
+    /// - `TimeError::TimeCalculationError`: If the calculated MSD is out of expected bounds.
+    pub fn now_with_clock_and_model<C>(clock: &C, model: &TimeModel) -> Result<Self, TimeError>
+        where C: Clock + ?Sized
+    {
+        Self::from_instant_with_model(MarsInstant::now_with_clock(clock)?, model)
+    }
+
+    /// Takes a snapshot of the provided instant.
+    ///
+    /// # Errors
+    ///
+    /// - `TimeError::TimeCalculationError`: If the calculated MSD is out of expected bounds.
+    pub fn from_instant(instant: MarsInstant) -> Result<Self, TimeError> {
+        Self::from_instant_with_model(instant, &TimeModel::MARS24)
+    }
+
+    /// Same as `MarsNow::from_instant` under the provided time model.
+    ///
+    /// # Errors
+    ///
+    /// - `TimeError::TimeCalculationError`: If the calculated MSD is out of expected bounds.
+    pub fn from_instant_with_model(
+        instant: MarsInstant,
+        model: &TimeModel
+    ) -> Result<Self, TimeError> {
+        let msd = epoch_to_msd_with_model(instant.epoch(), model)?;
+
+        Ok(Self {
+            instant,
+            msd,
+            mtc: sol_fraction_to_time(msd),
+            model: *model,
+        })
+    }
+
+    /// Returns the areocentric solar longitude (Ls) in degrees. Computed on demand, so taking a
+    /// snapshot only for the sol or MTC doesn't pay for it.
+    ///
+    /// # Errors
+    ///
+    /// - `TimeError::InvalidArgumentError`: If the MSD is not finite.
+    pub fn ls(&self) -> Result<f64, TimeError> {
+        msd_to_ls_with_model(self.msd, &self.model)
+    }
+
+    /// Returns the Mars Year (MY) number. Computed on demand, like `ls`.
+    ///
+    /// # Errors
+    ///
+    /// - `TimeError::TimeCalculationError`: If the start of the Mars Year could not be determined.
+    pub fn mars_year(&self) -> Result<i32, TimeError> {
+        msd_to_mars_year_with_model(self.msd, &self.model)
+    }
+
+    /// Returns the Local Mean Solar Time (LMST) at a given planetocentric east longitude.
+    ///
+    /// # Errors
+    ///
+    /// - `TimeError::InvalidArgumentError`: If the longitude is out of range.
+    pub fn lmst(&self, east_longitude: f64) -> Result<Time, TimeError> {
+        lmst(self.msd, east_longitude)
+    }
+
+    /// Returns the Local True Solar Time (LTST) at a given planetocentric east longitude.
+    ///
+    /// # Errors
+    ///
+    /// - `TimeError::InvalidArgumentError`: If the longitude is out of range.
+    pub fn ltst(&self, east_longitude: f64) -> Result<Time, TimeError> {
+        ltst_with_model(self.msd, east_longitude, &self.model)
+    }
+
+    /// Returns the local time in the provided Mars time zone.
+    pub fn zone_time(&self, zone: &MarsTimeZone) -> Time {
+        sol_fraction_to_time(self.msd + zone.offset_hours() / 24.0)
+    }
+}
+
+impl TryFrom<SystemTime> for MarsNow {
+    type Error = TimeError;
+
+    fn try_from(time: SystemTime) -> Result<Self, TimeError> {
+        Self::from_instant(MarsInstant::from(time))
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+/// Mars time zone, either one of the 24 Mars24 zones 15° of longitude wide and a whole Mars hour
+/// apart, or an arbitrary offset matching the local mean solar time of a longitude.
+///
+/// Standard zones are numbered by their offset from MTC in Mars hours, from -11 (west) to 12
+/// (east, shared with -12 across the 180° meridian).
+///
+/// # Examples
+///
+/// ```
+/// use martian::time::{ MarsTimeZone, Time };
+///
+/// // Zone of the Curiosity Rover at Gale crater, 137.4417°E
+/// let zone = MarsTimeZone::for_longitude(137.4417).unwrap();
+/// assert_eq!(zone.to_string(), "MTC+9");
+///
+/// let local = zone.mtc_to_zone_time(Time::new(20, 30, 0, 0)).unwrap();
+/// assert_eq!(local, Time::new(5, 30, 0, 0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MarsTimeZone {
+    offset_hours: f64,
+    zone: Option<i8>,
+}
+
+impl MarsTimeZone {
+    /// Coordinated Mars Time (MTC), the zone of the Airy-0 prime meridian.
+    pub const MTC: MarsTimeZone = MarsTimeZone { offset_hours: 0.0, zone: Some(0) };
+
+    /// Constructs a standard Mars time zone from its offset from MTC in whole Mars hours.
+    ///
+    /// # Errors
+    ///
+    /// - `TimeError::InvalidArgumentError`: If the zone is below -11 or above 12.
+    pub fn from_zone(zone: i8) -> Result<Self, TimeError> {
+        if !(-11..=12).contains(&zone) {
+            return Err(TimeError::InvalidArgumentError);
+        }
+
+        Ok(Self { offset_hours: f64::from(zone), zone: Some(zone) })
+    }
+
+    /// Looks up the standard Mars time zone containing a planetocentric east longitude.
+    ///
+    /// # Errors
+    ///
+    /// - `TimeError::InvalidArgumentError`: If the longitude is not finite or its absolute value
+    ///   exceeds 360 degrees.
+    pub fn for_longitude(east_longitude: f64) -> Result<Self, TimeError> {
+        let offset_hours = Self::longitude_to_offset_hours(east_longitude)?;
+
+        // -12 and 12 are the same zone centered on the 180° meridian
+        let zone = offset_hours.round() as i8;
+        Self::from_zone(if zone == -12 { 12 } else { zone })
+    }
+
+    /// Constructs a time zone offset from MTC by exactly the local mean solar time of a
+    /// planetocentric east longitude, eg. to follow a rover's LMST.
+    ///
+    /// # Errors
+    ///
+    /// - `TimeError::InvalidArgumentError`: If the longitude is not finite or its absolute value
+    ///   exceeds 360 degrees.
+    pub fn from_longitude(east_longitude: f64) -> Result<Self, TimeError> {
+        let offset_hours = Self::longitude_to_offset_hours(east_longitude)?;
+        Ok(Self { offset_hours, zone: None })
+    }
+
+    /// Returns the standard zone number, `None` for zones built from an arbitrary longitude.
+    pub fn zone(&self) -> Option<i8> {
+        self.zone
+    }
+
+    /// Returns the offset from MTC in Mars hours.
+    pub fn offset_hours(&self) -> f64 {
+        self.offset_hours
+    }
+
+    /// Returns the offset from MTC.
+    pub fn offset(&self) -> MarsDuration {
+        MarsDuration::from_mars_hours(self.offset_hours)
+    }
+
+    /// Converts Coordinated Mars Time (MTC) to the local time of this zone.
+    ///
+    /// # Errors
+    ///
+    /// - `TimeError::InvalidArgumentError`: If any of the time components is out of range.
+    pub fn mtc_to_zone_time(&self, mtc: Time) -> Result<Time, TimeError> {
+        // Counted in whole milliseconds, the offset of a standard zone is a whole Mars hour
+        let mtc_milliseconds = (mtc_to_msd(0, mtc)? * 86_400_000.0).round() as i64;
+        let offset_milliseconds = (self.offset_hours * 3_600_000.0).round() as i64;
+        let milliseconds = (mtc_milliseconds + offset_milliseconds).rem_euclid(86_400_000);
+
+        Ok(milliseconds_to_time(milliseconds as u64))
+    }
+
+    /// Converts Mars Sol Date (MSD) to the local time of this zone.
+    ///
+    /// # Errors
+    ///
+    /// - `TimeError::InvalidArgumentError`: If the MSD is not finite.
+    pub fn msd_to_zone_time(&self, msd: f64) -> Result<Time, TimeError> {
+        if !msd.is_finite() {
+            return Err(TimeError::InvalidArgumentError);
+        }
+
+        Ok(sol_fraction_to_time(msd + self.offset_hours / 24.0))
+    }
+
+    /// Returns how far ahead the local time of `other` is compared to this zone, negative when
+    /// `other` lies to the west.
+    pub fn time_difference(&self, other: &MarsTimeZone) -> MarsDuration {
+        MarsDuration::from_mars_hours(other.offset_hours - self.offset_hours)
+    }
+
+    // Offset of the local mean solar time from MTC, normalised to (-12, 12] Mars hours
+    fn longitude_to_offset_hours(east_longitude: f64) -> Result<f64, TimeError> {
+        if !east_longitude.is_finite() || east_longitude.abs() > 360.0 {
+            return Err(TimeError::InvalidArgumentError);
+        }
+
+        let offset_hours = (east_longitude / MARS_TIME_ZONE_WIDTH).rem_euclid(24.0);
+        Ok(if offset_hours > 12.0 { offset_hours - 24.0 } else { offset_hours })
+    }
+}
+
+impl fmt::Display for MarsTimeZone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.zone {
+            Some(0) => write!(f, "MTC"),
+            Some(zone) => write!(f, "MTC{:+}", zone),
+            None => {
+                let sign = if self.offset_hours < 0.0 { "-" } else { "+" };
+                let offset = (self.offset_hours.abs() * 3_600_000.0).round() as u64;
+                let offset = milliseconds_to_time(offset);
+                let (hours, minutes, seconds) = (offset.hours, offset.minutes, offset.seconds);
+                write!(f, "MTC{}{:02}:{:02}:{:02}", sign, hours, minutes, seconds)
+            }
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+/// Time scale of the Julian Dates a `TimeModel` counts sols from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JulianDateScale {
+    /// Terrestrial Time (TT), used by Mars24 and Allison (1997).
+    Tt,
+    /// Barycentric Dynamical Time (TDB), used by SPICE based pipelines.
+    Tdb,
+    /// Coordinated Universal Time (UTC), used by pipelines that skip the TT correction.
+    Utc,
+}
+
+impl From<JulianDateScale> for TimeScale {
+    fn from(scale: JulianDateScale) -> Self {
+        match scale {
+            JulianDateScale::Tt => TimeScale::TT,
+            JulianDateScale::Tdb => TimeScale::TDB,
+            JulianDateScale::Utc => TimeScale::UTC,
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+/// Constants relating Earth time to Mars Sol Date (MSD), as
+/// `MSD = (JD - jd_on_sol_zero) / sol_in_earth_days` with JD in the model time scale.
+///
+/// Functions depending on the model have a `*_with_model` variant, the others use
+/// `TimeModel::MARS24`. MTC, LMST and local sol are derived from the MSD alone so they don't need
+/// one given an MSD, but reading the clock does, so the `*_now` functions and `MarsNow` have
+/// model variants as well.
+///
+/// # Examples
+///
+/// ```
+/// use martian::time::{ utc_to_msd, utc_to_msd_with_model, JulianDateScale, TimeModel };
+///
+/// let datetime = "2012-08-06T05:17:57Z";
+/// let legacy = utc_to_msd_with_model(datetime, &TimeModel::ALLISON_1997).unwrap();
+/// println!("Mars24 {:.5}, Allison 1997 {:.5}", utc_to_msd(datetime).unwrap(), legacy);
+///
+/// // Pipeline feeding UTC Julian Dates to the Mars24 constants
+/// let model = TimeModel::custom(1.0274912517, 2405522.0028779, JulianDateScale::Utc).unwrap();
+/// println!("UTC based {:.5}", utc_to_msd_with_model(datetime, &model).unwrap());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimeModel {
+    sol_in_earth_days: f64,
+    jd_on_sol_zero: f64,
+    julian_date_scale: JulianDateScale,
+    // Same constants in nanoseconds, with sol zero counted from MJD 0, for `MarsSolDate`
+    sol_nanoseconds: u64,
+    sol_zero_mjd_nanoseconds: i128,
+}
+
+impl TimeModel {
+    /// Mars24 model from Allison & McEwen (2000) with the Mars24 prime meridian offset.
+    pub const MARS24: Self = Self {
+        sol_in_earth_days: SOL_IN_EARTH_DAYS,
+        jd_on_sol_zero: JD_ON_SOL_ZERO,
+        julian_date_scale: JulianDateScale::Tt,
+        sol_nanoseconds: 88_775_244_146_880,
+        sol_zero_mjd_nanoseconds: 477_057_848_650_560_000,
+    };
+
+    /// Model from Allison (1997), with the older sol length and prime meridian offset.
+    pub const ALLISON_1997: Self = Self {
+        sol_in_earth_days: ALLISON_1997_SOL_IN_EARTH_DAYS,
+        jd_on_sol_zero: ALLISON_1997_JD_ON_SOL_ZERO,
+        julian_date_scale: JulianDateScale::Tt,
+        sol_nanoseconds: 88_775_244_000_000,
+        sol_zero_mjd_nanoseconds: 477_057_833_694_720_000,
+    };
+
+    /// Constructs a new `TimeModel` instance from custom constants. `MarsSolDate` uses the
+    /// constants rounded to the nearest nanosecond, an `f64` Julian Date itself only resolves
+    /// about 40 µs.
+    ///
+    /// # Arguments
+    ///
+    /// * `sol_in_earth_days` - Length of a sol in Earth days.
+    /// * `jd_on_sol_zero` - Julian Date where MSD is zero.
+    /// * `julian_date_scale` - Time scale of the Julian Dates.
+    ///
+    /// # Errors
+    ///
+    /// - `TimeError::InvalidArgumentError`: If the sol length is not positive or any of the
+    ///   values is not finite.
+    pub fn custom(
+        sol_in_earth_days: f64,
+        jd_on_sol_zero: f64,
+        julian_date_scale: JulianDateScale
+    ) -> Result<Self, TimeError> {
+        let sol_nanoseconds = (sol_in_earth_days * NANOSECONDS_PER_DAY).round();
+        if !(1.0..1.0e18).contains(&sol_nanoseconds) || !jd_on_sol_zero.is_finite() {
+            return Err(TimeError::InvalidArgumentError);
+        }
+
+        // Splitting off MJD 0 is exact and keeps the nanoseconds within f64 precision
+        let sol_zero_mjd = jd_on_sol_zero - JD_ON_MJD_ZERO;
+
+        Ok(Self {
+            sol_in_earth_days,
+            jd_on_sol_zero,
+            julian_date_scale,
+            sol_nanoseconds: sol_nanoseconds as u64,
+            sol_zero_mjd_nanoseconds: (sol_zero_mjd * NANOSECONDS_PER_DAY).round() as i128,
+        })
+    }
+
+    /// Returns the length of a sol in Earth days.
+    pub fn sol_in_earth_days(&self) -> f64 {
+        self.sol_in_earth_days
+    }
+
+    /// Returns the Julian Date where MSD is zero.
+    pub fn jd_on_sol_zero(&self) -> f64 {
+        self.jd_on_sol_zero
+    }
+
+    /// Returns the length of a sol in Earth nanoseconds, as used by `MarsSolDate`.
+    pub fn sol_nanoseconds(&self) -> u64 {
+        self.sol_nanoseconds
+    }
+
+    /// Returns the time scale of the Julian Dates.
+    pub fn julian_date_scale(&self) -> JulianDateScale {
+        self.julian_date_scale
+    }
+
+    /// Converts a Mars Sol Date (MSD) to a Julian Date in the model time scale.
+    pub fn msd_to_jd(&self, msd: f64) -> f64 {
+        msd * self.sol_in_earth_days + self.jd_on_sol_zero
+    }
+
+    /// Converts a Julian Date in the model time scale to a Mars Sol Date (MSD).
+    pub fn jd_to_msd(&self, jd: f64) -> f64 {
+        (jd - self.jd_on_sol_zero) / self.sol_in_earth_days
+    }
+
+    // Start of sol zero in the model time scale
+    fn sol_zero(&self) -> Epoch {
+        let time_scale = TimeScale::from(self.julian_date_scale);
+        let mjd_zero = Epoch::from_jde_in_time_scale(JD_ON_MJD_ZERO, time_scale);
+
+        mjd_zero + Duration::from_total_nanoseconds(self.sol_zero_mjd_nanoseconds)
+    }
+}
+
+impl Default for TimeModel {
+    fn default() -> Self {
+        Self::MARS24
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+/// Mars Sol Date (MSD) split into a whole sol and the Earth (SI) nanoseconds elapsed since the
+/// start of that sol, under the Mars24 time model. The sol date doesn't record a model, so
+/// other models go through the `*_with_model` functions taking an `f64` MSD instead.
+///
+/// Converting a hifitime `Epoch` to a `MarsSolDate` and back is exact to the nanosecond for
+/// every date hifitime can represent, as both are integer counts of nanoseconds.
+///
+/// An `f64` MSD carries about 16 significant digits, so it rounds present day dates by up to
+/// 0.4 µs and dates up to the year 3000 by up to 3 µs. The time module converts through this
+/// representation and only rounds at the `f64` boundary.
+///
+/// Sol dates are limited to the range of a hifitime `Epoch`, about 3.28 million Earth years
+/// either side of 1900, so MSDs from about -1.1647e9 to 1.1648e9 sols round-trip. `from_msd`
+/// rejects anything further out.
+///
+/// # Examples
+///
+/// ```
+/// use martian::time::MarsSolDate;
+///
+/// let sol_date = MarsSolDate::from_utc("2012-08-06T05:17:57.123456789Z").unwrap();
+/// println!("Sol {} + {} ns", sol_date.sol(), sol_date.nanoseconds());
+///
+/// // No precision is lost on the way back
+/// let epoch = sol_date.to_epoch();
+/// assert_eq!(MarsSolDate::from_epoch(epoch), sol_date);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MarsSolDate {
+    sol: i64,
+    nanoseconds: u64,
+}
+
+impl MarsSolDate {
+    /// Constructs a new `MarsSolDate` instance from a hifitime `Epoch` in any time scale.
+    pub fn from_epoch(epoch: Epoch) -> Self {
+        Self::from_epoch_with_model(epoch, &TimeModel::MARS24)
+    }
+
+    // Sol date under the provided time model, for the `*_with_model` functions. Models counting
+    // in TDB are limited by the TT to TDB conversion of hifitime.
+    pub(crate) fn from_epoch_with_model(epoch: Epoch, model: &TimeModel) -> Self {
+        let time_scale = TimeScale::from(model.julian_date_scale());
+        let elapsed = (epoch.to_time_scale(time_scale) - model.sol_zero()).total_nanoseconds();
+        let sol_nanoseconds = model.sol_nanoseconds() as i128;
+
+        Self {
+            sol: elapsed.div_euclid(sol_nanoseconds) as i64,
+            nanoseconds: elapsed.rem_euclid(sol_nanoseconds) as u64,
+        }
+    }
+
+    /// Constructs a new `MarsSolDate` instance from an ISO8601 formatted UTC datetime string.
+    ///
+    /// # Errors
+    ///
+    /// - `TimeError::ISO8601FormatError`: If the provided date does not match the ISO8601 format.
+    /// - `TimeError::InvalidDateTimeField`: If a datetime field is out of range.
+    pub fn from_utc(datetime: &str) -> Result<Self, TimeError> {
+        Ok(Self::from_epoch(parse_utc_epoch(datetime)?))
+    }
+
+    /// Constructs a new `MarsSolDate` instance from an `f64` Mars Sol Date (MSD), rounded to the
+    /// nearest nanosecond.
+    ///
+    /// # Errors
+    ///
+    /// - `TimeError::InvalidArgumentError`: If the MSD is not finite.
+    /// - `TimeError::TimeCalculationError`: If the MSD is outside the range of a hifitime `Epoch`.
+    pub fn from_msd(msd: f64) -> Result<Self, TimeError> {
+        Self::from_msd_with_model(msd, &TimeModel::MARS24)
+    }
+
+    // Sol date under the provided time model, rounded to the nearest nanosecond
+    pub(crate) fn from_msd_with_model(msd: f64, model: &TimeModel) -> Result<Self, TimeError> {
+        if !msd.is_finite() {
+            return Err(TimeError::InvalidArgumentError);
+        }
+
+        // Subtracting the whole sol is exact, so only the fraction gets rounded
+        let sol = msd.floor();
+        let nanoseconds = ((msd - sol) * model.sol_nanoseconds() as f64).round() as u64;
+
+        // Fraction may round up to a whole sol
+        let sol_date = if nanoseconds >= model.sol_nanoseconds() {
+            Self { sol: sol as i64 + 1, nanoseconds: 0 }
+        } else {
+            Self { sol: sol as i64, nanoseconds }
+        };
+
+        // Casting to i64 and building the epoch both saturate, so a sol date outside the range
+        // of a hifitime `Epoch` doesn't come back unchanged
+        let epoch = sol_date.to_epoch_with_model(model);
+        if Self::from_epoch_with_model(epoch, model) != sol_date {
+            return Err(TimeError::TimeCalculationError);
+        }
+
+        Ok(sol_date)
+    }
+
+    /// Returns the whole sol number.
+    pub fn sol(&self) -> i64 {
+        self.sol
+    }
+
+    /// Returns the Earth (SI) nanoseconds elapsed since the start of the sol.
+    pub fn nanoseconds(&self) -> u64 {
+        self.nanoseconds
+    }
+
+    /// Returns the hifitime `Epoch` of the sol date, in Terrestrial Time (TT).
+    pub fn to_epoch(&self) -> Epoch {
+        self.to_epoch_with_model(&TimeModel::MARS24)
+    }
+
+    // Epoch of a sol date taken under the provided time model, in the model time scale
+    pub(crate) fn to_epoch_with_model(self, model: &TimeModel) -> Epoch {
+        let sol_nanoseconds = model.sol_nanoseconds() as i128;
+        let elapsed = self.sol as i128 * sol_nanoseconds + self.nanoseconds as i128;
+
+        model.sol_zero() + Duration::from_total_nanoseconds(elapsed)
+    }
+
+    /// Returns the sol date as an `f64` Mars Sol Date (MSD).
+    pub fn to_msd(&self) -> f64 {
+        self.to_msd_with_model(&TimeModel::MARS24)
+    }
+
+    // MSD of a sol date taken under the provided time model
+    pub(crate) fn to_msd_with_model(self, model: &TimeModel) -> f64 {
+        self.sol as f64 + self.nanoseconds as f64 / model.sol_nanoseconds() as f64
+    }
+
+    /// Returns the ISO8601 formatted UTC datetime string.
+    pub fn to_utc(&self) -> String {
+        self.to_epoch().to_time_scale(TimeScale::UTC).to_isoformat()
+    }
+}
+
+impl From<MarsSolDate> for MarsInstant {
+    fn from(sol_date: MarsSolDate) -> Self {
+        Self::from_epoch(sol_date.to_epoch())
+    }
+}
+
+impl From<Epoch> for MarsSolDate {
+    fn from(epoch: Epoch) -> Self {
+        Self::from_epoch(epoch)
+    }
+}
+
+impl From<SystemTime> for MarsSolDate {
+    fn from(time: SystemTime) -> Self {
+        Self::from_epoch(epoch_from_system_time(time))
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+/// Represents a span of time measured on the Martian clock. One sol is divided into 24 Mars
+/// hours, each with 60 Mars minutes of 60 Mars seconds, so every unit is `SOL_IN_EARTH_DAYS`
+/// times longer than its Earth counterpart.
+///
+/// # Examples
+///
+/// ```
+/// use martian::time::{ MarsDuration, MarsInstant };
+///
+/// let instant = MarsInstant::from_msd(53626.0).unwrap();
+/// let later = instant + MarsDuration::from_sols(3.0) + MarsDuration::from_mars_hours(4.0);
+///
+/// println!("{}", later - instant); // 3 sols 04:00:00:000
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct MarsDuration {
+    duration: Duration,
+}
+
+impl MarsDuration {
+    /// Constructs a new `MarsDuration` instance from a hifitime `Duration` in Earth SI seconds.
+    pub fn from_duration(duration: Duration) -> Self {
+        Self { duration }
+    }
+
+    /// Constructs a new `MarsDuration` instance from a number of sols.
+    ///
+    /// # Panics
+    ///
+    /// If the number of sols is not finite. Use `try_from_sols` for unchecked input.
+    pub fn from_sols(sols: f64) -> Self {
+        Self::from_duration(Duration::from_days(sols * SOL_IN_EARTH_DAYS))
+    }
+
+    /// Constructs a new `MarsDuration` instance from a number of Mars hours (1/24 of a sol).
+    ///
+    /// # Panics
+    ///
+    /// If the number of Mars hours is not finite. Use `try_from_mars_hours` for unchecked input.
+    pub fn from_mars_hours(hours: f64) -> Self {
+        Self::from_sols(hours / 24.0)
+    }
+
+    /// Constructs a new `MarsDuration` instance from a number of Mars minutes.
+    ///
+    /// # Panics
+    ///
+    /// If the number of Mars minutes is not finite. Use `try_from_mars_minutes` for unchecked
+    /// input.
+    pub fn from_mars_minutes(minutes: f64) -> Self {
+        Self::from_sols(minutes / 1440.0)
+    }
+
+    /// Constructs a new `MarsDuration` instance from a number of Mars seconds.
+    ///
+    /// # Panics
+    ///
+    /// If the number of Mars seconds is not finite. Use `try_from_mars_seconds` for unchecked
+    /// input.
+    pub fn from_mars_seconds(seconds: f64) -> Self {
+        Self::from_sols(seconds / 86400.0)
+    }
+
+    /// Constructs a new `MarsDuration` instance from a number of sols.
+    ///
+    /// # Errors
+    ///
+    /// - `TimeError::InvalidArgumentError`: If the number of sols is not finite.
+    pub fn try_from_sols(sols: f64) -> Result<Self, TimeError> {
+        if !sols.is_finite() {
+            return Err(TimeError::InvalidArgumentError);
+        }
+
+        Ok(Self::from_sols(sols))
+    }
+
+    /// Constructs a new `MarsDuration` instance from a number of Mars hours (1/24 of a sol).
+    ///
+    /// # Errors
+    ///
+    /// - `TimeError::InvalidArgumentError`: If the number of Mars hours is not finite.
+    pub fn try_from_mars_hours(hours: f64) -> Result<Self, TimeError> {
+        Self::try_from_sols(hours / 24.0)
+    }
+
+    /// Constructs a new `MarsDuration` instance from a number of Mars minutes.
+    ///
+    /// # Errors
+    ///
+    /// - `TimeError::InvalidArgumentError`: If the number of Mars minutes is not finite.
+    pub fn try_from_mars_minutes(minutes: f64) -> Result<Self, TimeError> {
+        Self::try_from_sols(minutes / 1440.0)
+    }
+
+    /// Constructs a new `MarsDuration` instance from a number of Mars seconds.
+    ///
+    /// # Errors
+    ///
+    /// - `TimeError::InvalidArgumentError`: If the number of Mars seconds is not finite.
+    pub fn try_from_mars_seconds(seconds: f64) -> Result<Self, TimeError> {
+        Self::try_from_sols(seconds / 86400.0)
+    }
+
+    /// Returns the duration as a hifitime `Duration` in Earth SI seconds.
+    pub fn to_duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Returns the duration in sols.
+    pub fn sols(&self) -> f64 {
+        self.duration.to_unit(Unit::Day) / SOL_IN_EARTH_DAYS
+    }
+
+    /// Returns the duration in Mars hours.
+    pub fn mars_hours(&self) -> f64 {
+        self.sols() * 24.0
+    }
+
+    /// Returns the duration in Mars minutes.
+    pub fn mars_minutes(&self) -> f64 {
+        self.sols() * 1440.0
+    }
+
+    /// Returns the duration in Mars seconds.
+    pub fn mars_seconds(&self) -> f64 {
+        self.sols() * 86400.0
+    }
+
+    /// Returns the absolute value of the duration.
+    pub fn abs(&self) -> Self {
+        Self::from_duration(self.duration.abs())
+    }
+
+    /// Splits the absolute value of the duration into whole sols and the remaining time on
+    /// the Martian clock.
+    pub fn to_sols_and_time(&self) -> (u64, Time) {
+        let total_milliseconds = (self.sols().abs() * 86_400_000.0).round() as u64;
+
+        let sols = total_milliseconds / 86_400_000;
+
+        (sols, milliseconds_to_time(total_milliseconds))
+    }
+}
+
+impl From<Duration> for MarsDuration {
+    fn from(duration: Duration) -> Self {
+        Self::from_duration(duration)
+    }
+}
+
+impl From<MarsDuration> for Duration {
+    fn from(duration: MarsDuration) -> Self {
+        duration.to_duration()
+    }
+}
+
+impl Add for MarsDuration {
+    type Output = MarsDuration;
+
+    fn add(self, other: MarsDuration) -> MarsDuration {
+        MarsDuration::from_duration(self.duration + other.duration)
+    }
+}
+
+impl AddAssign for MarsDuration {
+    fn add_assign(&mut self, other: MarsDuration) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for MarsDuration {
+    type Output = MarsDuration;
+
+    fn sub(self, other: MarsDuration) -> MarsDuration {
+        MarsDuration::from_duration(self.duration - other.duration)
+    }
+}
+
+impl SubAssign for MarsDuration {
+    fn sub_assign(&mut self, other: MarsDuration) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for MarsDuration {
+    type Output = MarsDuration;
+
+    fn neg(self) -> MarsDuration {
+        MarsDuration::from_duration(-self.duration)
+    }
+}
+
+// Panics if the factor is not finite, hifitime would silently saturate the duration instead
+impl Mul<f64> for MarsDuration {
+    type Output = MarsDuration;
+
+    fn mul(self, factor: f64) -> MarsDuration {
+        assert!(factor.is_finite(), "MarsDuration multiplied by a non-finite factor");
+
+        MarsDuration::from_duration(self.duration * factor)
+    }
+}
+
+// Panics if the factor is not finite
+impl Mul<MarsDuration> for f64 {
+    type Output = MarsDuration;
+
+    fn mul(self, duration: MarsDuration) -> MarsDuration {
+        duration * self
+    }
+}
+
+impl fmt::Display for MarsDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.duration.is_negative() { "-" } else { "" };
+        let (sols, time) = self.to_sols_and_time();
+
+        write!(f, "{}{} sols {}", sign, sols, time)
+    }
+}
+
+#[cfg(test)]
+mod mars_instant_tests {
+    use super::*;
+    use crate::time::clock::FixedClock;
+    use crate::time::functions::{
+        ltst,
+        msd_now_with_clock,
+        msd_to_utc,
+        mtc_now_with_clock,
+        utc_to_msd,
+    };
+
+    #[test]
+    fn test_mars_instant_from_utc() {
+        let date_time = "2024-11-07T17:58:40.000";
+        let instant = MarsInstant::from_utc(date_time).unwrap();
+
+        assert_eq!(instant.msd(), utc_to_msd(date_time).unwrap());
+        assert_eq!(instant.utc(), "2024-11-07T17:58:40.000000");
+    }
+
+    #[test]
+    fn test_mars_instant_from_msd() {
+        let msd = 53626.0011;
+        let instant = MarsInstant::from_msd(msd).unwrap();
+
+        assert!((instant.msd() - msd).abs() < 1.0e-9, "MSD: {}", instant.msd());
+
+        let expected = Epoch::from_gregorian_str(&msd_to_utc(msd).unwrap()).unwrap();
+        let result = Epoch::from_gregorian_str(&instant.utc()).unwrap();
+        assert!((expected - result).abs().to_seconds() <= 1.0);
+    }
+
+    #[test]
+    fn test_mars_instant_now() {
+        let clock = FixedClock::from_utc("2012-08-06T05:17:57Z").unwrap();
+        let instant = MarsInstant::now_with_clock(&clock).unwrap();
+
+        assert!((instant.msd() - msd_now_with_clock(&clock).unwrap()).abs() < 1.0e-9);
+        assert_eq!(instant.mtc(), mtc_now_with_clock(&clock).unwrap());
+
+        assert!(MarsInstant::now().unwrap() > instant);
+    }
+
+    #[test]
+    fn test_mars_instant_accessors() {
+        let instant = MarsInstant::from_msd(49269.25).unwrap();
+
+        assert_eq!(instant.mtc(), Time::new(6, 0, 0, 0));
+        assert_eq!(instant.lmst(137.4417).unwrap(), lmst(49269.25, 137.4417).unwrap());
+        assert_eq!(instant.ltst(137.4417).unwrap(), ltst(49269.25, 137.4417).unwrap());
+        assert_eq!(instant.mars_year().unwrap(), 31);
+        assert!((instant.ls().unwrap() - 150.7).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_mars_instant_ordering_across_time_scales() {
+        let utc = MarsInstant::from_utc("2012-08-06T05:17:57.000").unwrap();
+        let tt = MarsInstant::from_jde_tt(utc.jde_tt()).unwrap();
+        let later = MarsInstant::from_msd(utc.msd() + 1.0).unwrap();
+
+        assert!((utc.epoch() - tt.epoch()).abs().to_seconds() < 1.0e-3);
+        assert!(later > utc);
+
+        let mut instants = [later, utc];
+        instants.sort();
+        assert_eq!(instants[0], utc);
+    }
+
+    #[test]
+    fn test_mars_instant_invalid_arguments() {
+        assert_eq!(MarsInstant::from_msd(f64::NAN).unwrap_err(), TimeError::InvalidArgumentError);
+        assert_eq!(
+            MarsInstant::from_jde_tt(f64::INFINITY).unwrap_err(),
+            TimeError::InvalidArgumentError
+        );
+        assert_eq!(MarsInstant::from_utc("not a date").unwrap_err(), TimeError::ISO8601FormatError);
+    }
+
+    #[test]
+    fn test_mars_instant_msd_is_finite() {
+        // Ends of the range hifitime can represent
+        for duration in [Duration::MIN, Duration::MAX] {
+            let instant = MarsInstant::from_epoch(Epoch::from_tai_duration(duration));
+
+            assert!(instant.msd().is_finite(), "MSD: {}", instant.msd());
+            assert!(instant.msd_with_model(&TimeModel::ALLISON_1997).is_finite());
+            assert!(instant.ls().is_ok());
+        }
+    }
+
+    #[test]
+    fn test_mars_instant_from_system_time() {
+        let time = SystemTime::UNIX_EPOCH + std::time::Duration::new(1344230277, 500_000_000);
+        let instant = MarsInstant::from(time);
+
+        assert_eq!(instant, MarsInstant::from_utc("2012-08-06T05:17:57.5Z").unwrap());
+        assert_eq!(instant.sol_date(), MarsSolDate::from(time));
+        assert!(MarsInstant::from(SystemTime::now()) > instant);
+    }
+
+    #[test]
+    fn test_mars_instant_with_model() {
+        let model = TimeModel::ALLISON_1997;
+        let instant = MarsInstant::from_msd_with_model(49269.25, &model).unwrap();
+
+        assert!((instant.msd_with_model(&model) - 49269.25).abs() < 1.0e-9);
+        assert!((instant.msd_with_model(&TimeModel::MARS24) - instant.msd()).abs() < 1.0e-12);
+        assert_ne!(instant, MarsInstant::from_msd(49269.25).unwrap());
+
+        let result = MarsInstant::from_msd_with_model(f64::NAN, &model);
+        assert_eq!(result.unwrap_err(), TimeError::InvalidArgumentError);
+    }
+
+    #[test]
+    fn test_mars_instant_from_msd_limits() {
+        // A hifitime `Epoch` spans about 1.16 billion sols either side of J1900
+        for msd in [-1.16e9, 1.16e9] {
+            let instant = MarsInstant::from_msd(msd).unwrap();
+            assert_eq!(MarsInstant::from_msd(instant.msd()).unwrap(), instant);
+            assert!((instant.msd() - msd).abs() < 1.0e-6, "{}", msd);
+        }
+
+        for msd in [-1.17e9, 1.17e9, 1.0e20, -1.0e300] {
+            let result = MarsInstant::from_msd(msd);
+            assert_eq!(result.unwrap_err(), TimeError::TimeCalculationError, "{}", msd);
+        }
+    }
+}
+
+#[cfg(test)]
+mod mars_sol_date_tests {
+    use super::*;
+
+    // Spread over several centuries, with awkward sub-second parts
+    fn epochs() -> Vec<Epoch> {
+        let mut epochs = Vec::new();
+
+        for year in [1600, 1750, 1873, 1900, 1972, 2000, 2024, 2100, 2250, 2400, 2999] {
+            for nanoseconds in [0, 1, 123_456_789, 999_999_999] {
+                epochs.push(Epoch::from_gregorian_utc(year, 7, 14, 23, 59, 59, nanoseconds));
+            }
+        }
+
+        epochs
+    }
+
+    fn models() -> [TimeModel; 3] {
+        [
+            TimeModel::MARS24,
+            TimeModel::ALLISON_1997,
+            TimeModel::custom(1.0274912517, 2405522.0028779, JulianDateScale::Utc).unwrap(),
+        ]
+    }
+
+    #[test]
+    fn test_sol_zero() {
+        // JD_TT 2405522.0028779
+        let epoch = Epoch::from_gregorian(1873, 12, 29, 12, 4, 8, 650_560_000, TimeScale::TT);
+        let sol_date = MarsSolDate::from_epoch(epoch);
+
+        assert_eq!((sol_date.sol(), sol_date.nanoseconds()), (0, 0));
+        assert_eq!(sol_date.to_epoch(), epoch);
+
+        let sol_date = MarsSolDate::from_epoch(epoch - Unit::Nanosecond * 1);
+        let sol_nanoseconds = TimeModel::MARS24.sol_nanoseconds();
+        assert_eq!((sol_date.sol(), sol_date.nanoseconds()), (-1, sol_nanoseconds - 1));
+    }
+
+    #[test]
+    fn test_model_nanosecond_constants() {
+        for model in [TimeModel::MARS24, TimeModel::ALLISON_1997] {
+            let custom = TimeModel::custom(
+                model.sol_in_earth_days(),
+                model.jd_on_sol_zero(),
+                model.julian_date_scale()
+            ).unwrap();
+
+            // Presets are exact, custom constants are limited by the f64 Julian Date
+            assert_eq!(custom.sol_nanoseconds, model.sol_nanoseconds);
+            let difference = custom.sol_zero_mjd_nanoseconds - model.sol_zero_mjd_nanoseconds;
+            assert!(difference.abs() < 25_000, "{}", difference);
+        }
+    }
+
+    #[test]
+    fn test_nanosecond_round_trip() {
+        for model in models() {
+            for epoch in epochs() {
+                let sol_date = MarsSolDate::from_epoch_with_model(epoch, &model);
+                let result = sol_date.to_epoch_with_model(&model);
+
+                assert_eq!(result - epoch, Duration::ZERO, "{:?} {}", model, epoch);
+                assert_eq!(MarsSolDate::from_epoch_with_model(result, &model), sol_date);
+            }
+        }
+    }
+
+    #[test]
+    fn test_nanosecond_resolution() {
+        for epoch in epochs() {
+            let sol_date = MarsSolDate::from_epoch(epoch);
+            let next = MarsSolDate::from_epoch(epoch + Unit::Nanosecond * 1);
+
+            let elapsed = (next.sol() - sol_date.sol()) as i128 *
+                TimeModel::MARS24.sol_nanoseconds() as i128 +
+                next.nanoseconds() as i128 -
+                sol_date.nanoseconds() as i128;
+            assert_eq!(elapsed, 1, "{}", epoch);
+        }
+    }
+
+    #[test]
+    fn test_f64_msd_precision() {
+        // f64 MSDs are off by at most half a unit in the last place plus the rounding to ns
+        for epoch in epochs() {
+            let msd = MarsSolDate::from_epoch(epoch).to_msd();
+            let result = MarsSolDate::from_msd(msd).unwrap().to_epoch();
+
+            let ulp = msd.abs() * f64::EPSILON * SOL_IN_EARTH_DAYS * Unit::Day;
+            assert!((result - epoch).abs() <= ulp + Unit::Nanosecond * 1, "{}", epoch);
+        }
+
+        let sol_date = MarsSolDate::from_msd(-0.25).unwrap();
+        let sol_nanoseconds = TimeModel::MARS24.sol_nanoseconds();
+        assert_eq!((sol_date.sol(), sol_date.nanoseconds()), (-1, sol_nanoseconds / 4 * 3));
+
+        // Fractions within half a nanosecond of the next sol round up to it
+        let sol_date = MarsSolDate::from_msd(53626.0 - 1.0e-12).unwrap();
+        assert_eq!((sol_date.sol(), sol_date.nanoseconds()), (53626, 0));
+
+        let result = MarsSolDate::from_msd(f64::NAN);
+        assert_eq!(result.unwrap_err(), TimeError::InvalidArgumentError);
+
+        // The sol would saturate to i64::MAX instead of failing
+        let result = MarsSolDate::from_msd(1.0e300);
+        assert_eq!(result.unwrap_err(), TimeError::TimeCalculationError);
+
+        for msd in [-1.1647e9, 1.1648e9] {
+            let sol_date = MarsSolDate::from_msd(msd).unwrap();
+            assert_eq!(MarsSolDate::from_epoch(sol_date.to_epoch()), sol_date);
+            assert_eq!(sol_date.to_msd(), msd);
+        }
+
+        for msd in [-1.1648e9, 1.1649e9] {
+            let result = MarsSolDate::from_msd(msd);
+            assert_eq!(result.unwrap_err(), TimeError::TimeCalculationError, "{}", msd);
+        }
+    }
+
+    #[test]
+    fn test_mars_sol_date_conversions() {
+        let sol_date = MarsSolDate::from_utc("2012-08-06T05:17:57.123456789Z").unwrap();
+        let instant = MarsInstant::from(sol_date);
+
+        assert_eq!(instant.sol_date(), sol_date);
+        assert_eq!(MarsSolDate::from(instant.epoch()), sol_date);
+        assert_eq!(sol_date.to_utc(), "2012-08-06T05:17:57.123456");
+        assert!((sol_date.to_msd() - instant.msd()).abs() < 1.0e-12);
+    }
+}
+
+#[cfg(test)]
+mod mars_now_tests {
+    use super::*;
+    use crate::time::functions::{ msd_to_mtc, mtc_to_msd };
+    use crate::time::clock::FixedClock;
+
+    #[test]
+    fn test_mars_now_from_one_instant() {
+        let clock = FixedClock::from_utc("2024-11-07T17:58:40.000").unwrap();
+        let now = MarsNow::now_with_clock(&clock).unwrap();
+        let instant = MarsInstant::now_with_clock(&clock).unwrap();
+
+        assert_eq!(now.instant, instant);
+        assert_eq!(now.msd, instant.msd());
+        assert_eq!(now.mtc, instant.mtc());
+        assert_eq!(now.ls().unwrap(), instant.ls().unwrap());
+        assert_eq!(now.mars_year().unwrap(), instant.mars_year().unwrap());
+        assert_eq!(now.lmst(77.4509).unwrap(), instant.lmst(77.4509).unwrap());
+        assert_eq!(now.ltst(77.4509).unwrap(), instant.ltst(77.4509).unwrap());
+        assert_eq!(now.mars_year().unwrap(), 37);
+    }
+
+    #[test]
+    fn test_mars_now_with_model() {
+        let clock = FixedClock::from_utc("2024-11-07T17:58:40.000").unwrap();
+        let legacy = TimeModel::ALLISON_1997;
+        let now = MarsNow::now_with_clock_and_model(&clock, &legacy).unwrap();
+        let msd = now.instant.msd_with_model(&legacy);
+
+        assert_eq!(now.model, legacy);
+        assert_eq!(now.msd, msd);
+        assert_eq!(now.mtc, msd_to_mtc(msd).unwrap());
+        assert_eq!(now.ls().unwrap(), msd_to_ls_with_model(msd, &legacy).unwrap());
+        assert_eq!(now.mars_year().unwrap(), msd_to_mars_year_with_model(msd, &legacy).unwrap());
+        assert_eq!(now.ltst(77.4509).unwrap(), ltst_with_model(msd, 77.4509, &legacy).unwrap());
+
+        assert_eq!(MarsNow::from_instant_with_model(now.instant, &legacy).unwrap(), now);
+        assert_eq!(MarsNow::now_with_clock(&clock).unwrap().model, TimeModel::MARS24);
+        assert_eq!(MarsNow::now_with_model(&legacy).unwrap().model, legacy);
+    }
+
+    #[test]
+    fn test_mars_now_around_midnight() {
+        // Last millisecond before MTC midnight
+        let midnight = MarsInstant::from_msd(53626.0).unwrap();
+        let now = MarsNow::from_instant(midnight - MarsDuration::from_mars_seconds(0.001)).unwrap();
+
+        assert_eq!(now.msd.floor(), 53625.0);
+        assert_eq!(now.mtc, Time::new(23, 59, 59, 999));
+    }
+
+    #[test]
+    fn test_mars_now_sub_millisecond_before_midnight() {
+        // Rounding to the nearest millisecond would show the midnight of a sol not yet reached
+        let midnight = MarsInstant::from_msd(53625.0).unwrap();
+        let instant = midnight - MarsDuration::from_mars_seconds(0.0004);
+        let now = MarsNow::from_instant(instant).unwrap();
+
+        assert_eq!(now.msd.floor(), 53624.0);
+        assert_eq!(now.mtc, Time::new(23, 59, 59, 999));
+        assert_eq!(instant.mtc(), now.mtc);
+        assert_eq!(msd_to_mtc(now.msd).unwrap(), now.mtc);
+        assert!((mtc_to_msd(53624, now.mtc).unwrap() - now.msd).abs() < 1.0 / 86_400_000.0);
+    }
+
+    #[test]
+    fn test_mars_now_from_system_time() {
+        let time = SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1344230277);
+        let now = MarsNow::try_from(time).unwrap();
+
+        let instant = MarsInstant::from_utc("2012-08-06T05:17:57Z").unwrap();
+        assert_eq!(now, MarsNow::from_instant(instant).unwrap());
+    }
+
+    #[test]
+    fn test_mars_now_system_clock() {
+        let now = MarsNow::now().unwrap();
+
+        // Sol for 2024-11-07T17:58:40.000, so the system clock is past it
+        assert!(now.msd > 53626.0011);
+        assert!(now.mars_year().unwrap() >= 37);
+    }
+}
+
+#[cfg(test)]
+mod mars_time_zone_tests {
+    use super::*;
+
+    #[test]
+    fn test_mars_time_zone_for_longitude() {
+        let cases = [
+            (0.0, 0),
+            (7.4, 0),
+            (7.6, 1),
+            (137.4417, 9),
+            (-137.4417, -9),
+            (222.5583, -9),
+            (77.4509, 5),
+            (180.0, 12),
+            (-180.0, 12),
+            (-172.0, -11),
+            (360.0, 0),
+        ];
+
+        for (east_longitude, zone) in cases {
+            let result = MarsTimeZone::for_longitude(east_longitude).unwrap();
+            assert_eq!(result.zone(), Some(zone), "Longitude {}", east_longitude);
+            assert_eq!(result.offset_hours(), f64::from(zone));
+        }
+
+        for east_longitude in [361.0, f64::NAN] {
+            let result = MarsTimeZone::for_longitude(east_longitude);
+            assert_eq!(result.unwrap_err(), TimeError::InvalidArgumentError);
+        }
+    }
+
+    #[test]
+    fn test_mars_time_zone_from_zone() {
+        assert_eq!(MarsTimeZone::from_zone(0).unwrap(), MarsTimeZone::MTC);
+        assert!(MarsTimeZone::from_zone(-11).is_ok());
+        assert!(MarsTimeZone::from_zone(12).is_ok());
+        assert_eq!(MarsTimeZone::from_zone(-12).unwrap_err(), TimeError::InvalidArgumentError);
+        assert_eq!(MarsTimeZone::from_zone(13).unwrap_err(), TimeError::InvalidArgumentError);
+    }
+
+    #[test]
+    fn test_mars_time_zone_display() {
+        assert_eq!(MarsTimeZone::MTC.to_string(), "MTC");
+        assert_eq!(MarsTimeZone::from_zone(9).unwrap().to_string(), "MTC+9");
+        assert_eq!(MarsTimeZone::from_zone(-5).unwrap().to_string(), "MTC-5");
+
+        // 137.4417° => +9h 9m 46.008s
+        let zone = MarsTimeZone::from_longitude(137.4417).unwrap();
+        assert_eq!(zone.zone(), None);
+        assert_eq!(zone.to_string(), "MTC+09:09:46");
+        assert_eq!(MarsTimeZone::from_longitude(-7.5).unwrap().to_string(), "MTC-00:30:00");
+    }
+
+    #[test]
+    fn test_mars_time_zone_conversions() {
+        let zone = MarsTimeZone::from_zone(-5).unwrap();
+        assert_eq!(zone.mtc_to_zone_time(Time::new(3, 15, 0, 0)).unwrap(), Time::new(22, 15, 0, 0));
+        assert_eq!(zone.msd_to_zone_time(49269.25).unwrap(), Time::new(1, 0, 0, 0));
+
+        // Arbitrary offset zone matches LMST
+        let zone = MarsTimeZone::from_longitude(137.4417).unwrap();
+        assert_eq!(zone.msd_to_zone_time(49269.25).unwrap(), lmst(49269.25, 137.4417).unwrap());
+        assert_eq!(zone.mtc_to_zone_time(Time::new(6, 0, 0, 0)).unwrap(), Time::new(15, 9, 46, 8));
+
+        let invalid = zone.mtc_to_zone_time(Time::new(24, 0, 0, 0));
+        assert_eq!(invalid.unwrap_err(), TimeError::InvalidArgumentError);
+        assert_eq!(zone.msd_to_zone_time(f64::NAN).unwrap_err(), TimeError::InvalidArgumentError);
+    }
+
+    #[test]
+    fn test_mars_time_zone_time_difference() {
+        // Perseverance at Jezero crater and Curiosity at Gale crater
+        let jezero = MarsTimeZone::for_longitude(77.4509).unwrap();
+        let gale = MarsTimeZone::for_longitude(137.4417).unwrap();
+
+        assert_eq!(jezero.time_difference(&gale), MarsDuration::from_mars_hours(4.0));
+        assert_eq!(gale.time_difference(&jezero), MarsDuration::from_mars_hours(-4.0));
+        assert_eq!(gale.time_difference(&gale), MarsDuration::default());
+
+        let jezero = MarsTimeZone::from_longitude(77.4509).unwrap();
+        let gale = MarsTimeZone::from_longitude(137.4417).unwrap();
+        let difference = jezero.time_difference(&gale).mars_hours();
+        assert!((difference - 59.9908 / 15.0).abs() < 1.0e-9);
+    }
+
+    #[test]
+    fn test_mars_now_zone_time() {
+        let now = MarsNow::from_instant(MarsInstant::from_msd(49269.25).unwrap()).unwrap();
+        let zone = MarsTimeZone::from_zone(9).unwrap();
+
+        assert_eq!(now.zone_time(&MarsTimeZone::MTC), now.mtc);
+        assert_eq!(now.zone_time(&zone), Time::new(15, 0, 0, 0));
+    }
+}
+
+#[cfg(test)]
+mod mars_duration_tests {
+    use super::*;
+
+    #[test]
+    fn test_mars_duration_units() {
+        let duration = MarsDuration::from_sols(1.0);
+
+        assert!((duration.to_duration().to_seconds() - 88775.244).abs() < 0.001);
+        assert!((duration.mars_hours() - 24.0).abs() < 1.0e-9);
+        assert!((MarsDuration::from_mars_hours(1.0).sols() - 1.0 / 24.0).abs() < 1.0e-12);
+        assert!((MarsDuration::from_mars_minutes(90.0).mars_hours() - 1.5).abs() < 1.0e-9);
+        assert!((MarsDuration::from_mars_seconds(30.0).mars_minutes() - 0.5).abs() < 1.0e-9);
+    }
+
+    #[test]
+    fn test_mars_duration_non_finite() {
+        assert_eq!(MarsDuration::try_from_sols(1.0).unwrap(), MarsDuration::from_sols(1.0));
+
+        for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            let result = MarsDuration::try_from_sols(value);
+            assert_eq!(result.unwrap_err(), TimeError::InvalidArgumentError);
+
+            let result = MarsDuration::try_from_mars_hours(value);
+            assert_eq!(result.unwrap_err(), TimeError::InvalidArgumentError);
+
+            let result = MarsDuration::try_from_mars_minutes(value);
+            assert_eq!(result.unwrap_err(), TimeError::InvalidArgumentError);
+
+            let result = MarsDuration::try_from_mars_seconds(value);
+            assert_eq!(result.unwrap_err(), TimeError::InvalidArgumentError);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_mars_duration_from_sols_nan() {
+        MarsDuration::from_sols(f64::NAN);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_mars_duration_from_mars_hours_infinity() {
+        MarsDuration::from_mars_hours(f64::INFINITY);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_mars_duration_mul_nan() {
+        let _ = MarsDuration::from_sols(1.0) * f64::NAN;
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_mars_duration_mul_infinity() {
+        let _ = f64::NEG_INFINITY * MarsDuration::from_sols(1.0);
+    }
+
+    #[test]
+    fn test_mars_duration_conversion() {
+        let duration = Duration::from_hours(10.0);
+        let mars_duration = MarsDuration::from(duration);
+
+        assert_eq!(Duration::from(mars_duration), duration);
+        assert!((mars_duration.sols() - 10.0 / 24.0 / SOL_IN_EARTH_DAYS).abs() < 1.0e-12);
+    }
+
+    #[test]
+    fn test_mars_duration_arithmetic() {
+        let three_sols = MarsDuration::from_sols(3.0);
+        let four_hours = MarsDuration::from_mars_hours(4.0);
+
+        let sum = three_sols + four_hours;
+        assert!((sum.mars_hours() - 76.0).abs() < 1.0e-9);
+        assert!(((sum - four_hours).sols() - 3.0).abs() < 1.0e-12);
+        assert!(((three_sols * 2.0).sols() - 6.0).abs() < 1.0e-12);
+        assert!(((0.5 * three_sols).sols() - 1.5).abs() < 1.0e-12);
+        assert!(((-three_sols).sols() + 3.0).abs() < 1.0e-12);
+        assert!(four_hours < three_sols);
+    }
+
+    #[test]
+    fn test_mars_duration_with_instants() {
+        let instant = MarsInstant::from_msd(53626.0).unwrap();
+        let duration = MarsDuration::from_sols(3.0) + MarsDuration::from_mars_hours(4.0);
+
+        let later = instant + duration;
+        assert!((later.msd() - (53626.0 + 3.0 + 4.0 / 24.0)).abs() < 1.0e-8);
+        assert_eq!(later.mtc(), Time::new(4, 0, 0, 0));
+
+        let difference = later - instant;
+        assert!((difference.sols() - duration.sols()).abs() < 1.0e-9);
+        assert!(((instant - later).sols() + duration.sols()).abs() < 1.0e-9);
+        assert_eq!(later - duration, instant);
+
+        let mut moving = instant;
+        moving += MarsDuration::from_sols(1.0);
+        moving -= MarsDuration::from_sols(1.0);
+        assert_eq!(moving, instant);
+    }
+
+    #[test]
+    fn test_mars_duration_display() {
+        let duration = MarsDuration::from_sols(3.0) + MarsDuration::from_mars_hours(4.0);
+        assert_eq!(duration.to_string(), "3 sols 04:00:00:000");
+
+        let duration = -MarsDuration::from_mars_seconds(61.5);
+        assert_eq!(duration.to_string(), "-0 sols 00:01:01:500");
+        assert_eq!(duration.to_sols_and_time(), (0, Time::new(0, 1, 1, 500)));
+    }
+}